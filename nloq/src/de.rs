@@ -1,9 +1,28 @@
+use alloc::borrow::Cow;
 use alloc::collections::VecDeque;
 use alloc::vec::Vec;
-use nl_parser::{parse_next, Parsed};
-use object_query::Query;
-
-/// Deserializes a str into an iterator of query parts
+use nl_parser::{parse_next, unescape_str, Parsed, ORDINALS};
+use object_query::{AccessNext, AccessNextMut, Query};
+
+/// How many queries `access` and `access_mut` apply at most. Each query takes a stack frame, so
+/// longer paths are refused rather than risking a stack overflow
+const MAX_ACCESS_DEPTH: usize = 128;
+
+/// Deserializes a str into an iterator of query parts. The iterator yields the queries in the
+/// order they are written as they are parsed, borrowing keys from the source without collecting
+/// them
+///
+/// ```
+/// # use nloq::Deserializer;
+/// # use object_query::Query;
+/// let mut keys = 0;
+/// Deserializer::from_str("the name of the first user").for_each(|query| {
+///     if query.is_key() {
+///         keys += 1;
+///     }
+/// });
+/// assert_eq!(keys, 2);
+/// ```
 pub struct Deserializer<'de> {
     src: &'de str,
     first: bool,
@@ -12,6 +31,7 @@ pub struct Deserializer<'de> {
 
 impl<'de> Deserializer<'de> {
     /// Construct a Deserializer for a string slice
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(src: &'de str) -> Self {
         Self {
             src,
@@ -21,15 +41,71 @@ impl<'de> Deserializer<'de> {
     }
 
     /// Get the resulting query. Keep in mind this is the reverse of the iterator due to the nature
-    /// of the `of` relationships
+    /// of the `of` relationships, so the outermost query comes first and "the name of the user"
+    /// becomes `["user", "name"]`, the order needed to access an object with
     pub fn query(&mut self) -> Vec<Query<'de>> {
-        let mut out = VecDeque::new();
-        while let Some(query) = self.next() {
+        let mut out = VecDeque::with_capacity(self.max_remaining());
+        for query in self.by_ref() {
             out.push_front(query)
         }
         out.into()
     }
 
+    /// Get the queries in the order they are written, which is the order of the iterator. The
+    /// outermost query comes last so "the name of the user" becomes `["name", "user"]`
+    pub fn query_in_reading_order(&mut self) -> Vec<Query<'de>> {
+        let mut out = Vec::with_capacity(self.max_remaining());
+        out.extend(self.by_ref());
+        out
+    }
+
+    /// An upper bound on how many queries are left. Every query after the first is introduced by
+    /// `of`, so this counts the occurrences of `of` in the rest, including those inside words and
+    /// strings
+    fn max_remaining(&self) -> usize {
+        let ofs = self.rest().matches("of").count();
+        if self.first {
+            ofs + 1
+        } else {
+            ofs
+        }
+    }
+
+    /// Access the item the remaining queries point to from `root` while parsing. The queries are
+    /// kept on the stack as they are parsed in reading order and then applied from the outermost
+    /// one, so no `Vec` is built. Paths of more than 128 queries give `None`
+    pub fn access<'v, T: AccessNext>(&mut self, root: &'v T) -> Option<&'v T> {
+        self.access_from(root, 0)
+    }
+
+    /// Mutably access the item the remaining queries point to from `root` while parsing. See
+    /// `access`
+    pub fn access_mut<'v, T: AccessNextMut>(&mut self, root: &'v mut T) -> Option<&'v mut T> {
+        self.access_mut_from(root, 0)
+    }
+
+    fn access_from<'v, T: AccessNext>(&mut self, root: &'v T, depth: usize) -> Option<&'v T> {
+        match self.next() {
+            Some(_) if depth >= MAX_ACCESS_DEPTH => None,
+            Some(query) => self.access_from(root, depth + 1)?.access_next(&query),
+            None => Some(root),
+        }
+    }
+
+    fn access_mut_from<'v, T: AccessNextMut>(
+        &mut self,
+        root: &'v mut T,
+        depth: usize,
+    ) -> Option<&'v mut T> {
+        match self.next() {
+            Some(_) if depth >= MAX_ACCESS_DEPTH => None,
+            Some(query) => self
+                .access_mut_from(root, depth + 1)?
+                .access_next_mut(&query),
+            None => Some(root),
+        }
+    }
+
     fn parse_next(&mut self) -> Option<Parsed<'de>> {
         if let Ok((_, parsed, rest)) = parse_next(self.rest()) {
             self.index += self.rest().len() - rest.len();
@@ -50,6 +126,52 @@ impl<'de> Deserializer<'de> {
     }
 }
 
+/// A key query from the contents of a string, where `\\` escapes the next character
+fn unescaped_key(key: &str) -> Query<'_> {
+    match unescape_str(key) {
+        Cow::Borrowed(key) => Query::key(key),
+        Cow::Owned(key) => Query::key_owned(key),
+    }
+}
+
+fn parse_ordinal_word(string: &str) -> Option<usize> {
+    if let Some(i) = ORDINALS.iter().position(|ordinal| *ordinal == string) {
+        return Some(i + 1);
+    }
+    Some(match string {
+        "thirteenth" => 13,
+        "fourteenth" => 14,
+        "fifteenth" => 15,
+        "sixteenth" => 16,
+        "seventeenth" => 17,
+        "eighteenth" => 18,
+        "nineteenth" => 19,
+        "twentieth" => 20,
+        "thirtieth" => 30,
+        "fortieth" => 40,
+        "fiftieth" => 50,
+        "sixtieth" => 60,
+        "seventieth" => 70,
+        "eightieth" => 80,
+        "ninetieth" => 90,
+        _ => return None,
+    })
+}
+
+fn parse_tens(string: &str) -> Option<usize> {
+    Some(match string {
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return None,
+    })
+}
+
 fn parse_index(string: &str) -> Option<usize> {
     if matches!(string.chars().next()?, '1'..='9') {
         if string.ends_with("th")
@@ -61,28 +183,34 @@ fn parse_index(string: &str) -> Option<usize> {
         } else {
             None
         }
+    } else if let Some(index) = parse_ordinal_word(string) {
+        Some(index)
     } else {
-        Some(match string {
-            "first" => 1,
-            "second" => 2,
-            "third" => 3,
-            "fourth" => 4,
-            "fifth" => 5,
-            "sixth" => 6,
-            "seventh" => 7,
-            "eighth" => 8,
-            "ninth" => 9,
-            "tenth" => 10,
-            "eleventh" => 11,
-            "twelfth" => 12,
-            _ => return None,
-        })
+        // compound ordinals such as "twenty first" or "twenty-first"
+        let (tens, unit) = string.split_once(|ch: char| ch == '-' || ch.is_whitespace())?;
+        let unit = parse_ordinal_word(unit.trim_start())?;
+        if unit < 10 {
+            Some(parse_tens(tens)? + unit)
+        } else {
+            None
+        }
     }
 }
 
+fn parse_hyphenated_from_last(string: &str) -> Option<usize> {
+    let index = string
+        .strip_suffix("-to-last")
+        .or_else(|| string.strip_suffix("-from-last"))?;
+    parse_index(index)
+}
+
 impl<'a> Iterator for Deserializer<'a> {
     type Item = Query<'a>;
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.max_remaining()))
+    }
+
     fn next(&mut self) -> Option<Self::Item> {
         let start_index = self.index;
 
@@ -99,17 +227,54 @@ impl<'a> Iterator for Deserializer<'a> {
             self.rollback(start_index);
             return None;
         }
-        let identifier = if let Some(identifier) = self.parse_next() {
-            identifier
-        } else {
-            self.rollback(start_index);
-            return None;
+        let identifier_start = self.index;
+        let identifier = match self.parse_next() {
+            Some(Parsed::Token(tens)) if parse_tens(tens).is_some() => {
+                // join the parts of a compound ordinal such as "twenty first", but only when an
+                // index follows so a bare key keeps to a single token
+                let tens_index = self.index;
+                match self.parse_next() {
+                    Some(Parsed::Token(unit)) if parse_ordinal_word(unit).is_some() => {
+                        let unit_index = self.index;
+                        match self.parse_next() {
+                            Some(Parsed::Token("item" | "to" | "from")) => {
+                                self.rollback(unit_index);
+                                Parsed::Token(self.src[identifier_start..unit_index].trim())
+                            }
+                            _ => {
+                                self.rollback(tens_index);
+                                Parsed::Token(tens)
+                            }
+                        }
+                    }
+                    _ => {
+                        self.rollback(tens_index);
+                        Parsed::Token(tens)
+                    }
+                }
+            }
+            Some(identifier) => identifier,
+            None => {
+                self.rollback(start_index);
+                return None;
+            }
         };
 
         let indentifier_index = self.index;
 
+        // hyphenated forms such as "the third-from-last item"
+        if let Parsed::Token(token) = identifier {
+            if let Some(index) = parse_hyphenated_from_last(token) {
+                if self.parse_next() == Some(Parsed::Token("item")) {
+                    self.first = false;
+                    return Some(Query::index_from_last(index - 1));
+                }
+                self.rollback(indentifier_index);
+            }
+        }
+
         match self.parse_next() {
-            Some(Parsed::Token("to")) => match self.parse_next() {
+            Some(Parsed::Token("to" | "from")) => match self.parse_next() {
                 Some(Parsed::Token("last")) => {
                     if self.parse_next() == Some(Parsed::Token("item")) {
                         match identifier {
@@ -126,7 +291,7 @@ impl<'a> Iterator for Deserializer<'a> {
                             Parsed::Str(key) => {
                                 self.first = false;
                                 self.rollback(indentifier_index);
-                                Some(Query::key(key))
+                                Some(unescaped_key(key))
                             }
                             _ => {
                                 self.rollback(start_index);
@@ -143,7 +308,7 @@ impl<'a> Iterator for Deserializer<'a> {
                             Parsed::Str(key) => {
                                 self.first = false;
                                 self.rollback(indentifier_index);
-                                Some(Query::key(key))
+                                Some(unescaped_key(key))
                             }
                             Parsed::Number(_) => {
                                 self.rollback(start_index);
@@ -161,7 +326,7 @@ impl<'a> Iterator for Deserializer<'a> {
                     Parsed::Str(key) => {
                         self.first = false;
                         self.rollback(indentifier_index);
-                        Some(Query::key(key))
+                        Some(unescaped_key(key))
                     }
                     Parsed::Number(_) => {
                         self.rollback(start_index);
@@ -187,7 +352,7 @@ impl<'a> Iterator for Deserializer<'a> {
                 Parsed::Str(key) => {
                     self.first = false;
                     self.rollback(indentifier_index);
-                    Some(Query::key(key))
+                    Some(unescaped_key(key))
                 }
                 Parsed::Number(_) => {
                     self.rollback(start_index);
@@ -198,17 +363,17 @@ impl<'a> Iterator for Deserializer<'a> {
                 Parsed::Token(key) => {
                     self.first = false;
                     self.rollback(indentifier_index);
-                    return Some(Query::key(key));
+                    Some(Query::key(key))
                 }
                 Parsed::Str(key) => {
                     self.first = false;
                     self.rollback(indentifier_index);
-                    return Some(Query::key(key));
+                    Some(unescaped_key(key))
                 }
                 Parsed::Number(_) => {
                     self.first = false;
                     self.rollback(start_index);
-                    return None;
+                    None
                 }
             },
         }
@@ -219,6 +384,7 @@ impl<'a> Iterator for Deserializer<'a> {
 mod tests {
     use super::*;
     use alloc::vec;
+    use serde_json::json;
 
     #[test]
     fn parse_indexes() {
@@ -234,6 +400,27 @@ mod tests {
         assert_eq!(parse_index("tenth"), Some(10));
         assert_eq!(parse_index("eleventh"), Some(11));
         assert_eq!(parse_index("twelfth"), Some(12));
+        assert_eq!(parse_index("thirteenth"), Some(13));
+        assert_eq!(parse_index("fourteenth"), Some(14));
+        assert_eq!(parse_index("fifteenth"), Some(15));
+        assert_eq!(parse_index("sixteenth"), Some(16));
+        assert_eq!(parse_index("seventeenth"), Some(17));
+        assert_eq!(parse_index("eighteenth"), Some(18));
+        assert_eq!(parse_index("nineteenth"), Some(19));
+        assert_eq!(parse_index("twentieth"), Some(20));
+        assert_eq!(parse_index("thirtieth"), Some(30));
+        assert_eq!(parse_index("fortieth"), Some(40));
+        assert_eq!(parse_index("fiftieth"), Some(50));
+        assert_eq!(parse_index("sixtieth"), Some(60));
+        assert_eq!(parse_index("seventieth"), Some(70));
+        assert_eq!(parse_index("eightieth"), Some(80));
+        assert_eq!(parse_index("ninetieth"), Some(90));
+
+        assert_eq!(parse_index("twenty first"), Some(21));
+        assert_eq!(parse_index("twenty-first"), Some(21));
+        assert_eq!(parse_index("thirty second"), Some(32));
+        assert_eq!(parse_index("forty-third"), Some(43));
+        assert_eq!(parse_index("ninety  ninth"), Some(99));
 
         assert_eq!(parse_index("1st"), Some(1));
         assert_eq!(parse_index("2nd"), Some(2));
@@ -259,6 +446,10 @@ mod tests {
         assert!(parse_index("1.1st").is_none());
         assert!(parse_index("0th").is_none());
         assert!(parse_index("01st").is_none());
+        assert!(parse_index("twenty").is_none());
+        assert!(parse_index("twenty tenth").is_none());
+        assert!(parse_index("twenty twentieth").is_none());
+        assert!(parse_index("first second").is_none());
     }
 
     #[test]
@@ -283,11 +474,74 @@ mod tests {
         assert_eq!(deserializer.next(), Some(Query::index_from_last(2)));
         assert!(deserializer.next().is_none());
 
+        let mut deserializer = Deserializer::from_str("the thirteenth item");
+        assert_eq!(deserializer.next(), Some(Query::index(12)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the twentieth to last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(19)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer =
+            Deserializer::from_str("the twenty first item of the thirty-second item");
+        assert_eq!(deserializer.next(), Some(Query::index(20)));
+        assert_eq!(deserializer.next(), Some(Query::index(31)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the forty third to last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(42)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the twenty first of the users");
+        assert_eq!(deserializer.next(), Some(Query::key("twenty")));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the twenty of the item");
+        assert_eq!(deserializer.next(), Some(Query::key("twenty")));
+        assert_eq!(deserializer.next(), Some(Query::key("item")));
+        assert!(deserializer.next().is_none());
+
         let mut deserializer = Deserializer::from_str("the first item of the last item");
         assert_eq!(deserializer.next(), Some(Query::index(0)));
         assert_eq!(deserializer.next(), Some(Query::index_from_last(0)));
         assert!(deserializer.next().is_none());
 
+        let mut deserializer = Deserializer::from_str("the third from last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(2)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the 42nd from last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(41)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the twenty first from last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(20)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the third-from-last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(2)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the 5th-to-last item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(4)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer =
+            Deserializer::from_str("the second from last item of the first item");
+        assert_eq!(deserializer.next(), Some(Query::index_from_last(1)));
+        assert_eq!(deserializer.next(), Some(Query::index(0)));
+        assert!(deserializer.next().is_none());
+
+        let mut deserializer = Deserializer::from_str("the key from the last item");
+        assert_eq!(deserializer.next(), Some(Query::key("key")));
+        assert!(deserializer.next().is_none());
+        assert_eq!(deserializer.rest(), "from the last item");
+
+        let mut deserializer = Deserializer::from_str("the third-from-last of the item");
+        assert_eq!(deserializer.next(), Some(Query::key("third-from-last")));
+        assert_eq!(deserializer.next(), Some(Query::key("item")));
+        assert!(deserializer.next().is_none());
+
         let mut deserializer = Deserializer::from_str("the 64th to last item of the sixth item");
         assert_eq!(deserializer.next(), Some(Query::index_from_last(63)));
         assert_eq!(deserializer.next(), Some(Query::index(5)));
@@ -339,6 +593,100 @@ mod tests {
         assert!(deserializer.next().is_none());
     }
 
+    #[test]
+    fn query_orders() {
+        let mut deserializer = Deserializer::from_str("the name of the user");
+        assert_eq!(
+            vec![Query::key("user"), Query::key("name")],
+            deserializer.query()
+        );
+
+        let mut deserializer = Deserializer::from_str("the name of the user");
+        assert_eq!(
+            vec![Query::key("name"), Query::key("user")],
+            deserializer.query_in_reading_order()
+        );
+    }
+
+    #[test]
+    fn size_hints() {
+        for src in &[
+            "",
+            "the name",
+            "the name of the user",
+            "the `name of` of the third item of the offers",
+            "the office of the last item of the 2nd item of the users of",
+            "of the name",
+            "the name of `x` of the",
+        ] {
+            let mut deserializer = Deserializer::from_str(src);
+            loop {
+                let (lower, upper) = deserializer.size_hint();
+                let remaining = Deserializer {
+                    src: deserializer.src,
+                    first: deserializer.first,
+                    index: deserializer.index,
+                }
+                .count();
+                assert!(lower <= remaining, "{}", src);
+                assert!(upper.unwrap() >= remaining, "{}", src);
+                if deserializer.next().is_none() {
+                    break;
+                }
+            }
+        }
+        assert_eq!(Deserializer::from_str("the name").size_hint(), (0, Some(1)));
+        assert_eq!(
+            Deserializer::from_str("the name of the user").size_hint(),
+            (0, Some(2))
+        );
+    }
+
+    #[test]
+    fn access_while_parsing() {
+        let mut value = json!({"users": [{"name": "alice"}, {"name": "bob"}]});
+
+        let mut deserializer = Deserializer::from_str("the name of the last item of the users");
+        assert_eq!(deserializer.access(&value), Some(&json!("bob")));
+        assert_eq!(deserializer.rest(), "");
+
+        let mut deserializer = Deserializer::from_str("the age of the first item of the users");
+        assert_eq!(deserializer.access(&value), None);
+
+        let mut deserializer = Deserializer::from_str("the first item of the users");
+        *deserializer.access_mut(&mut value).unwrap() = json!({"name": "carol"});
+        assert_eq!(
+            value,
+            json!({"users": [{"name": "carol"}, {"name": "bob"}]})
+        );
+
+        assert_eq!(Deserializer::from_str("").access(&value), Some(&value));
+    }
+
+    #[test]
+    fn access_long_paths() {
+        let mut value = json!(1);
+        for _ in 0..MAX_ACCESS_DEPTH {
+            value = json!({ "a": value });
+        }
+        let path = "the a of ".repeat(MAX_ACCESS_DEPTH - 1) + "the a";
+        assert_eq!(
+            Deserializer::from_str(&path).access(&value),
+            Some(&json!(1))
+        );
+        assert_eq!(
+            Deserializer::from_str(&path).access_mut(&mut value),
+            Some(&mut json!(1))
+        );
+
+        // one query too many is refused without recursing any further
+        let path = "the a of ".repeat(MAX_ACCESS_DEPTH) + "the a";
+        assert_eq!(Deserializer::from_str(&path).access(&value), None);
+        let path = "the a of ".repeat(50000) + "the b";
+        assert_eq!(Deserializer::from_str(&path).access(&value), None);
+        assert_eq!(Deserializer::from_str(&path).access_mut(&mut value), None);
+    }
+
     #[test]
     fn query() {
         let mut deserializer =
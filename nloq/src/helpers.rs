@@ -1,5 +1,8 @@
 use crate::de::Deserializer;
+use crate::ser::write_queries;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::{self, Write};
 use core::str::{from_utf8, Utf8Error};
 use object_query::Query;
 
@@ -12,3 +15,16 @@ pub fn from_str<'a>(s: &'a str) -> Vec<Query<'a>> {
 pub fn from_slice<'a>(s: &'a [u8]) -> Result<Vec<Query<'a>>, Utf8Error> {
     Ok(from_str(from_utf8(s)?))
 }
+
+/// write a query as NLOQ to a writer. This is the inverse of `from_str`
+pub fn to_writer<W: Write>(mut writer: W, queries: &[Query<'_>]) -> fmt::Result {
+    write_queries(&mut writer, queries)
+}
+
+/// write a query as an NLOQ string. This is the inverse of `from_str`
+pub fn to_string(queries: &[Query<'_>]) -> String {
+    let mut out = String::new();
+    // writing to a string can't fail
+    let _ = to_writer(&mut out, queries);
+    out
+}
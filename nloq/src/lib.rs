@@ -1,5 +1,5 @@
 //! Parses natural language to produce a `Vec<Query>`. The main entrypoints are
-//! `from_slice` and `from_str`
+//! `from_slice` and `from_str`, with `to_string` doing the reverse
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "std")]
@@ -10,6 +10,8 @@ pub extern crate object_query;
 
 mod de;
 mod helpers;
+mod ser;
 
 pub use de::Deserializer;
 pub use helpers::*;
+pub use ser::NloqPath;
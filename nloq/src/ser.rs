@@ -0,0 +1,173 @@
+use core::fmt::{self, Write};
+use nl_parser::{ordinal, parse_number};
+use object_query::Query;
+
+/// Keys which are a single simple word can be written bare, everything else is quoted
+fn is_bare_key(key: &str) -> bool {
+    key.chars().next().is_some_and(char::is_alphabetic)
+        && key.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
+        && parse_number(key).is_err()
+}
+
+fn write_query<W: Write>(writer: &mut W, query: &Query<'_>) -> fmt::Result {
+    match query {
+        Query::Index {
+            index: 0,
+            from_last: true,
+        } => writer.write_str("the last item"),
+        Query::Index {
+            index,
+            from_last: true,
+        } => writer.write_fmt(format_args!("the {} to last item", ordinal(index + 1))),
+        Query::Index {
+            index,
+            from_last: false,
+        } => writer.write_fmt(format_args!("the {} item", ordinal(index + 1))),
+        Query::Key(key) if is_bare_key(key) => writer.write_fmt(format_args!("the {}", key)),
+        Query::Key(key) => writer.write_fmt(format_args!(
+            "the `{}`",
+            key.replace('\\', r"\\").replace('`', r"\`")
+        )),
+    }
+}
+
+/// Write the queries as NLOQ. The queries are expected in the order produced by
+/// `Deserializer::query`, so the last query is written first
+pub fn write_queries<W: Write>(writer: &mut W, queries: &[Query<'_>]) -> fmt::Result {
+    for (i, query) in queries.iter().rev().enumerate() {
+        if i > 0 {
+            writer.write_str(" of ")?;
+        }
+        write_query(writer, query)?;
+    }
+    Ok(())
+}
+
+/// A list of queries which displays as NLOQ. With the `serde` feature it serializes as the same
+/// string, so query paths can be embedded in other documents
+///
+/// ```
+/// # use nloq::{from_str, NloqPath};
+/// # use nloq::object_query::Query;
+/// let queries = [Query::key("name"), Query::index(0)];
+/// let path = NloqPath(&queries).to_string();
+/// assert_eq!(path, "the first item of the name");
+/// assert_eq!(from_str(&path), queries);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NloqPath<'a>(pub &'a [Query<'a>]);
+
+impl<'a> fmt::Display for NloqPath<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_queries(f, self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for NloqPath<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::*;
+    use alloc::string::String;
+    use alloc::vec;
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(ordinal(1), "first");
+        assert_eq!(ordinal(2), "second");
+        assert_eq!(ordinal(12), "twelfth");
+        assert_eq!(ordinal(13), "13th");
+        assert_eq!(ordinal(21), "21st");
+        assert_eq!(ordinal(22), "22nd");
+        assert_eq!(ordinal(23), "23rd");
+        assert_eq!(ordinal(111), "111th");
+        assert_eq!(ordinal(112), "112th");
+        assert_eq!(ordinal(101), "101st");
+    }
+
+    #[test]
+    fn serialize_query() {
+        assert_eq!(to_string(&[Query::index(0)]), "the first item");
+        assert_eq!(to_string(&[Query::index(41)]), "the 42nd item");
+        assert_eq!(to_string(&[Query::index_from_last(0)]), "the last item");
+        assert_eq!(
+            to_string(&[Query::index_from_last(2)]),
+            "the third to last item"
+        );
+        assert_eq!(to_string(&[Query::key("key")]), "the key");
+        assert_eq!(to_string(&[Query::key("multi word")]), "the `multi word`");
+        assert_eq!(to_string(&[Query::key("42")]), "the `42`");
+        assert_eq!(
+            to_string(&[Query::index(0), Query::index(1)]),
+            "the second item of the first item"
+        );
+        assert_eq!(to_string(&[]), "");
+    }
+
+    #[test]
+    fn round_trip() {
+        for src in &[
+            "the first item",
+            "the last item",
+            "the 42nd item",
+            "the third to last item",
+            "the first item of the last item",
+            "the 64th to last item of the sixth item",
+            "the key",
+            "the `multi word key`",
+            "the key of the `other key`",
+            "the name of the user of the `access event`",
+            "the key of the first item",
+            "the first item of the key",
+        ] {
+            let query = from_str(src);
+            assert_eq!(&to_string(&query), src);
+            assert_eq!(from_str(&to_string(&query)), query);
+        }
+
+        // the delimiter and the escape character are escaped
+        for key in &["a`b", "a\\", "`\\`", "\\\\"] {
+            let query = vec![Query::key(key)];
+            assert_eq!(from_str(&to_string(&query)), query);
+        }
+        assert_eq!(to_string(&[Query::key("a`b")]), r"the `a\`b`");
+        assert_eq!(to_string(&[Query::key("a\\")]), r"the `a\\`");
+
+        let query = from_str("the 1st item of the `2nd` of the item");
+        let string: String = to_string(&query);
+        assert_eq!(string, "the first item of the `2nd` of the item");
+        assert_eq!(from_str(&string), query);
+    }
+
+    #[test]
+    fn display_path() {
+        use alloc::string::ToString;
+
+        let query = from_str("the `multi word key` of the last item");
+        assert_eq!(
+            NloqPath(&query).to_string(),
+            "the `multi word key` of the last item"
+        );
+        assert_eq!(from_str(&NloqPath(&query).to_string()), query);
+        assert_eq!(NloqPath(&[]).to_string(), "");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_path() {
+        let query = from_str("the name of the third item of the users");
+        let string = serde_json::to_string(&NloqPath(&query)).unwrap();
+        assert_eq!(string, r#""the name of the third item of the users""#);
+        let parsed: String = serde_json::from_str(&string).unwrap();
+        assert_eq!(from_str(&parsed), query);
+    }
+}
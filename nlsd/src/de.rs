@@ -1,21 +1,29 @@
 use super::error::{Error, Result};
+use crate::naming::{
+    dehumanize_camel, dehumanize_camel_chars, dehumanize_snake, dehumanize_snake_chars,
+    humanize_eq, is_cardinal, parse_cardinal, parse_ordinal,
+};
+use crate::{Keywords, DEFAULT_MAX_DEPTH};
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
 use nl_parser::{
-    parse_next, parse_number, parse_string, parse_token, Number, ParseError, ParseResult, Parsed,
+    line_column, parse_f32, parse_next_with, parse_number, parse_string_with, parse_token,
+    unescape_str, Number, ParseError, ParseResult, Parsed, DEFAULT_DELIMITER, PERIOD,
 };
 use serde::de::{self, Deserialize};
 
-#[cfg(not(feature = "std"))]
-use num_traits::float::FloatCore;
-
 const TRUE: &str = "true";
 const FALSE: &str = "false";
 const ON: &str = "on";
 const OFF: &str = "off";
 const ENABLED: &str = "enabled";
 const DISABLED: &str = "disabled";
+const YES: &str = "yes";
+const NO: &str = "no";
 
 const EMPTY: &str = "empty";
 const NOTHING: &str = "nothing";
@@ -25,84 +33,137 @@ const OBJECT: &str = "object";
 const LIST: &str = "list";
 const HENCEFORTH: &str = "henceforth";
 const WHERE: &str = "where";
+const A: &str = "a";
 const AN: &str = "an";
-const ITEM: &str = "item";
 const OF: &str = "of";
 const WHICH: &str = "which";
 const IS: &str = "is";
 const AND: &str = "and";
 const ANOTHER: &str = "another";
 
+const VALUE_KEYWORDS: [&str; 9] = [TRUE, FALSE, ON, OFF, ENABLED, DISABLED, YES, NO, NOTHING];
+
 /// A structure that deserializes NLSD into Rust structures
 #[derive(Debug, Clone)]
 pub struct Deserializer<'de> {
     src: &'de str,
     index: usize,
+    case_sensitive: bool,
+    delimiter: char,
+    depth: usize,
+    max_depth: usize,
+    keywords: Keywords,
+    structural_keywords: StructuralKeywords,
+    number_words: bool,
+    deny_duplicate_keys: bool,
+}
+
+/// Pairs of the English keywords used internally with the words of the configured vocabulary
+type StructuralKeywords = [(&'static str, &'static str); 15];
+
+fn structural_keywords(keywords: &Keywords) -> StructuralKeywords {
+    [
+        (THE, keywords.the),
+        (OBJECT, keywords.object),
+        (LIST, keywords.list),
+        (EMPTY, keywords.empty),
+        (NOTHING, keywords.nothing),
+        (HENCEFORTH, keywords.henceforth),
+        (WHERE, keywords.r#where),
+        (A, keywords.a),
+        (AN, keywords.an),
+        (OF, keywords.of),
+        (WHICH, keywords.which),
+        (IS, keywords.is),
+        (AND, keywords.and),
+        (ANOTHER, keywords.another),
+        (keywords.item, keywords.item),
+    ]
 }
 
-fn unescape_str(string: &str) -> Cow<str> {
-    let out = string.replace(r#"\`"#, "`");
-    if out == string {
-        Cow::Borrowed(string)
-    } else {
-        Cow::Owned(out)
+/// Convert a float without a fractional part into an integer
+fn float_to_i128(num: f64) -> Result<i128> {
+    if num.trunc() != num {
+        return Err(Error::ExpectedInteger);
+    }
+    // `i128::MIN` is exactly representable unlike `i128::MAX`
+    if num < i128::MIN as f64 || num >= -(i128::MIN as f64) {
+        return Err(Error::NumberOutOfRange);
     }
+    Ok(num as i128)
 }
 
-fn dehumanize_snake(string: &str) -> String {
-    let mut out = String::new();
-    let mut was_whitespace = false;
-    for ch in string.chars() {
-        if ch.is_whitespace() {
-            was_whitespace = true;
-        } else {
-            if was_whitespace && !out.is_empty() {
-                out.push('_');
-            }
-            was_whitespace = false;
-            out.push(ch);
-        }
+/// Convert a positive float without a fractional part into an unsigned integer
+fn float_to_u128(num: f64) -> Result<u128> {
+    if num.trunc() != num {
+        return Err(Error::ExpectedInteger);
+    }
+    if num.is_sign_negative() {
+        return Err(Error::ExpectedUnsigned);
     }
-    out
+    // `u128::MAX` rounds up to 2^128 which is out of range
+    if num >= u128::MAX as f64 {
+        return Err(Error::NumberOutOfRange);
+    }
+    Ok(num as u128)
 }
 
-fn dehumanize_camel(string: &str) -> String {
-    let mut out = String::new();
-    let mut was_whitespace = false;
-    for ch in string.chars() {
-        if ch.is_whitespace() {
-            was_whitespace = true;
-        } else {
-            if was_whitespace || out.is_empty() {
-                ch.to_uppercase().for_each(|ch| out.push(ch));
-            } else {
-                out.push(ch);
-            }
-            was_whitespace = false;
+fn decode_bytes(string: &str) -> Result<Vec<u8>> {
+    fn hex_value(ch: u8) -> Result<u8> {
+        match ch {
+            b'0'..=b'9' => Ok(ch - b'0'),
+            b'a'..=b'f' => Ok(ch - b'a' + 10),
+            b'A'..=b'F' => Ok(ch - b'A' + 10),
+            _ => Err(Error::ExpectedBytes),
         }
     }
-    out
+    let pairs = string.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::ExpectedBytes);
+    }
+    pairs
+        .map(|pair| Ok(hex_value(pair[0])? << 4 | hex_value(pair[1])?))
+        .collect()
 }
 
+/// Find the candidate which is the string itself or its snake or camel case form. The forms are
+/// compared character by character so no strings are allocated
 fn dehumanize_match(string: &str, candidates: &[&'static str]) -> Option<&'static str> {
-    if let Some(string) = candidates.into_iter().find(|&&s| s == string) {
+    if let Some(string) = candidates.iter().find(|&&s| s == string) {
         return Some(string);
     }
-    let snake = dehumanize_snake(string);
-    if let Some(string) = candidates.into_iter().find(|&&s| s == snake) {
+    if let Some(string) = candidates
+        .iter()
+        .find(|s| s.chars().eq(dehumanize_snake_chars(string)))
+    {
         return Some(string);
     }
-    let camel = dehumanize_camel(string);
-    if let Some(string) = candidates.into_iter().find(|&&s| s == camel) {
+    if let Some(string) = candidates
+        .iter()
+        .find(|s| s.chars().eq(dehumanize_camel_chars(string)))
+    {
         return Some(string);
     }
-    None
+    // renamed fields may not follow either convention, so fall back to how they were written
+    candidates.iter().find(|s| humanize_eq(s, string)).copied()
 }
 
 impl<'de> Deserializer<'de> {
     /// Construct a new Deserializer from a string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(src: &'de str) -> Self {
-        Self { src, index: 0 }
+        Self {
+            src,
+            index: 0,
+            case_sensitive: false,
+            delimiter: DEFAULT_DELIMITER,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            keywords: Keywords::english(),
+            structural_keywords: structural_keywords(&Keywords::english()),
+            number_words: false,
+            deny_duplicate_keys: false,
+        }
     }
 
     /// Construct a new Deserializer from the byte representation of a string
@@ -110,42 +171,293 @@ impl<'de> Deserializer<'de> {
         Ok(Self {
             src: core::str::from_utf8(src)?,
             index: 0,
+            case_sensitive: false,
+            delimiter: DEFAULT_DELIMITER,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            keywords: Keywords::english(),
+            structural_keywords: structural_keywords(&Keywords::english()),
+            number_words: false,
+            deny_duplicate_keys: false,
         })
     }
 
+    /// Set whether keywords such as `the` or `where` must be lowercase. By default keywords are
+    /// matched case-insensitively so `The` is accepted at the start of a sentence. The contents of
+    /// backtick strings are never affected
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set the character which surrounds strings. Defaults to a backtick
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set how deeply lists and objects may be nested before deserializing fails with
+    /// `Error::DepthLimitExceeded`. Defaults to 128
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the noun used for the items of a list. Defaults to `item`
+    pub fn with_item_noun(mut self, item_noun: &'static str) -> Self {
+        self.keywords.item = item_noun;
+        self.structural_keywords = structural_keywords(&self.keywords);
+        self
+    }
+
+    /// Set the words which give the document its structure. Defaults to English
+    pub fn with_keywords(mut self, keywords: Keywords) -> Self {
+        self.keywords = keywords;
+        self.structural_keywords = structural_keywords(&keywords);
+        self
+    }
+
+    /// Set whether integers may be written in words, e.g. `twenty one` for 21. Defaults to `false`
+    pub fn with_number_words(mut self, number_words: bool) -> Self {
+        self.number_words = number_words;
+        self
+    }
+
+    /// Set whether an object repeating one of its keys fails with `Error::DuplicateKey`. By
+    /// default every entry is passed on and maps usually keep the last value. Defaults to `false`
+    pub fn with_deny_duplicate_keys(mut self, deny_duplicate_keys: bool) -> Self {
+        self.deny_duplicate_keys = deny_duplicate_keys;
+        self
+    }
+
     /// Get remaining string not deserialized yet
     #[inline]
     pub fn rest(&self) -> &'de str {
         &self.src[self.index..]
     }
 
+    /// Get the byte index into the source string of the remaining string
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Replace a token with the English keyword it matches, ignoring case unless keywords are
+    /// case-sensitive
+    fn keyword(&self, token: &'de str) -> &'de str {
+        let matches = |keyword: &str| {
+            if self.case_sensitive {
+                keyword == token
+            } else {
+                keyword.eq_ignore_ascii_case(token)
+            }
+        };
+        let mut is_replaced = false;
+        for (keyword, word) in &self.structural_keywords {
+            if matches(word) {
+                return keyword;
+            }
+            is_replaced = is_replaced || matches(keyword);
+        }
+        // an English keyword replaced by the vocabulary must not be mistaken for the keyword
+        if is_replaced {
+            return "";
+        }
+        if self.case_sensitive {
+            return token;
+        }
+        VALUE_KEYWORDS
+            .iter()
+            .find(|keyword| matches(keyword))
+            .map_or(token, |keyword| keyword)
+    }
+
+    /// The word of the configured vocabulary for an English keyword
+    fn localize(&self, keyword: &'static str) -> &'static str {
+        self.structural_keywords
+            .iter()
+            .find(|(english, _)| *english == keyword)
+            .map_or(keyword, |(_, word)| word)
+    }
+
+    /// The English article agreeing with the item noun
+    fn item_article(&self) -> &'static str {
+        if self.keywords.item_article() == self.keywords.a {
+            A
+        } else {
+            AN
+        }
+    }
+
+    fn keyword_parsed(&self, parsed: Parsed<'de>) -> Parsed<'de> {
+        match parsed {
+            Parsed::Token(token) => Parsed::Token(self.keyword(token)),
+            parsed => parsed,
+        }
+    }
+
     fn peek_next(&self) -> Result<Parsed<'de>> {
-        let (_, parsed, _) =
-            parse_next(self.rest()).map_err(|err| self.inc_err_index(err.into()))?;
-        Ok(parsed)
+        let (_, parsed, _) = parse_next_with(self.rest(), self.delimiter)
+            .map_err(|err| self.inc_err_index(err.into()))?;
+        Ok(self.keyword_parsed(parsed))
     }
 
     fn parse_next(&mut self) -> Result<Parsed<'de>> {
-        self.inc_parse_result(parse_next(self.rest()))
+        let parsed = self.inc_parse_result(parse_next_with(self.rest(), self.delimiter))?;
+        Ok(self.keyword_parsed(parsed))
     }
 
     fn parse_token(&mut self) -> Result<&'de str> {
-        self.inc_parse_result(parse_token(self.rest()))
+        let token = self.inc_parse_result(parse_token(self.rest()))?;
+        Ok(self.keyword(token))
     }
 
     fn parse_string(&mut self) -> Result<&'de str> {
-        self.inc_parse_result(parse_string(self.rest()))
+        self.inc_parse_result(parse_string_with(self.rest(), self.delimiter))
     }
 
     fn parse_number(&mut self) -> Result<Number> {
+        if let Some(num) = self.parse_number_words()? {
+            return Ok(num);
+        }
         self.inc_parse_result(parse_number(self.rest()))
     }
 
+    fn parse_f32(&mut self) -> Result<f32> {
+        if let Some(num) = self.parse_number_words()? {
+            return Ok(match num {
+                Number::Integer(num) => num as f32,
+                Number::Integer128(num) => num as f32,
+                Number::Unsigned128(num) => num as f32,
+                Number::Float(num) => num as f32,
+            });
+        }
+        self.inc_parse_result(parse_f32(self.rest()))
+    }
+
+    /// Whether the token is part of an integer written in words and these are enabled
+    fn is_number_word(&self, token: &str) -> bool {
+        if !self.number_words
+            || self.case_sensitive && token.bytes().any(|b| b.is_ascii_uppercase())
+        {
+            return false;
+        }
+        is_cardinal(token)
+    }
+
+    /// Parse the longest run of number words, e.g. `one hundred twenty-one`, if there is one
+    fn parse_number_words(&mut self) -> Result<Option<Number>> {
+        if !self.number_words {
+            return Ok(None);
+        }
+        let mut start = None;
+        let mut end = self.index;
+        let mut rest_index = self.index;
+        while let Ok((index, token, rest)) = parse_token(&self.src[rest_index..]) {
+            if !self.is_number_word(token) {
+                break;
+            }
+            start.get_or_insert(rest_index + index);
+            end = rest_index + index + token.len();
+            rest_index = self.src.len() - rest.len();
+        }
+        let start = match start {
+            Some(start) => start,
+            None => return Ok(None),
+        };
+        let words = &self.src[start..end];
+        let num = parse_cardinal(words).ok_or_else(|| Error::UnexpectedToken(words.to_string()))?;
+        self.index = rest_index;
+        Ok(Some(match i64::try_from(num) {
+            Ok(num) => Number::Integer(num),
+            Err(_) => Number::Unsigned128(num.into()),
+        }))
+    }
+
+    /// Parse a whole number which must fit into the signed integer `T`
+    fn parse_signed<T: TryFrom<i128>>(&mut self) -> Result<T> {
+        let num = match self.parse_number()? {
+            Number::Integer(num) => num.into(),
+            Number::Integer128(num) => num,
+            Number::Unsigned128(_) => return Err(Error::NumberOutOfRange),
+            Number::Float(num) => float_to_i128(num)?,
+        };
+        num.try_into().map_err(|_| Error::NumberOutOfRange)
+    }
+
+    /// Parse a whole number which must fit into the unsigned integer `T`
+    fn parse_unsigned<T: TryFrom<u128>>(&mut self) -> Result<T> {
+        let num = match self.parse_number()? {
+            Number::Integer(num) => i128::from(num)
+                .try_into()
+                .map_err(|_| Error::ExpectedUnsigned)?,
+            Number::Integer128(num) => num.try_into().map_err(|_| Error::ExpectedUnsigned)?,
+            Number::Unsigned128(num) => num,
+            Number::Float(num) => float_to_u128(num)?,
+        };
+        num.try_into().map_err(|_| Error::NumberOutOfRange)
+    }
+
     fn parse_and_expect_token(&mut self, token: &'static str) -> Result<()> {
         if self.parse_token()? == token {
             Ok(())
         } else {
-            Err(Error::ExpectedKeyWord(token))
+            Err(Error::ExpectedKeyWord(self.localize(token)))
+        }
+    }
+
+    /// The position of an ordinal token such as `second` or `2nd`, starting at 1
+    fn ordinal(&self, token: &str) -> Option<usize> {
+        if self.case_sensitive && token.bytes().any(|b| b.is_ascii_uppercase()) {
+            return None;
+        }
+        parse_ordinal(token)
+    }
+
+    /// Peek whether the next tokens are `the` followed by an ordinal and return its position
+    fn peek_the_ordinal(&mut self) -> Result<Option<usize>> {
+        if self.peek_next()? != Parsed::Token(THE) {
+            return Ok(None);
+        }
+        let start_index = self.index;
+        let _ = self.parse_token()?;
+        let ordinal = match self.peek_next() {
+            Ok(Parsed::Token(token)) => self.ordinal(token),
+            _ => None,
+        };
+        self.rollback(start_index);
+        Ok(ordinal)
+    }
+
+    /// Whether the next value is a `list` or `object` written without its `the`, e.g.
+    /// `list where an item is 1` or `empty object`
+    fn peek_bare_compound(&mut self) -> Result<bool> {
+        let start_index = self.index;
+        let mut next = self.peek_next()?;
+        if next == Parsed::Token(EMPTY) {
+            let _ = self.parse_token()?;
+            next = match self.peek_next() {
+                Ok(next) => next,
+                Err(_) => Parsed::Token(EMPTY),
+            };
+            self.rollback(start_index);
+        }
+        Ok(matches!(next, Parsed::Token(LIST) | Parsed::Token(OBJECT)))
+    }
+
+    /// Parse `the` and an ordinal which was peeked, returning the index of the ordinal
+    fn parse_the_ordinal(&mut self) -> Result<usize> {
+        let _ = self.parse_token()?;
+        let index = self.index;
+        let _ = self.parse_token()?;
+        Ok(index)
+    }
+
+    /// An error naming the token as written in the source starting at `index`
+    fn unexpected_token(&self, index: usize) -> Error {
+        match parse_token(&self.src[index..]) {
+            Ok((_, token, _)) => Error::UnexpectedToken(token.to_string()),
+            Err(err) => err.into(),
         }
     }
 
@@ -157,22 +469,108 @@ impl<'de> Deserializer<'de> {
 
     fn inc_err_index(&self, err: Error) -> Error {
         match err {
-            Error::Parse(err) => Error::Parse(match err {
-                ParseError::InvalidString(i) => ParseError::InvalidString(i + self.index),
-                ParseError::InvalidNumber(i) => ParseError::InvalidNumber(i + self.index),
-                ParseError::ExpectedWhitespace(i) => ParseError::ExpectedWhitespace(i + self.index),
-                err => err,
-            }),
+            Error::Parse(err) => match err.index() {
+                Some(index) => {
+                    let (line, column) = line_column(self.src, index + self.index);
+                    Error::Syntax {
+                        error: err.offset(self.index),
+                        line,
+                        column,
+                    }
+                }
+                // running out of input is only worth pointing out when there was none at all
+                None if self.src.trim().is_empty() => Error::EmptyInput,
+                None => Error::Parse(err),
+            },
             err => err,
         }
     }
 
+    /// Skip the period which may end a value like a sentence, e.g. `the list where an item is 1.`,
+    /// so that the remaining string starts at the next sentence
+    pub(crate) fn parse_terminal_period(&mut self) {
+        if let Ok((_, PERIOD, rest)) = parse_token(self.rest()) {
+            self.index += self.rest().len() - rest.len();
+        }
+    }
+
+    /// Whether the next token is a period ending the sentence, which also ends every list and
+    /// object still open
+    fn peek_terminal_period(&self) -> bool {
+        matches!(parse_token(self.rest()), Ok((_, PERIOD, _)))
+    }
+
     fn rollback(&mut self, index: usize) {
         self.index = index
     }
+
+    /// Skip over the next value without building it, e.g. for unknown fields. Lists and objects
+    /// are walked entry by entry so that their end is found the same way as when they are read
+    fn skip_value(&mut self) -> Result<()> {
+        match self.peek_next()? {
+            Parsed::Token(THE) => {
+                let start_index = self.index;
+                let _ = self.parse_token()?;
+                if let Parsed::Str(_) = self.parse_next()? {
+                    if let Ok(Parsed::Token(WHICH)) = self.peek_next() {
+                        let _ = self.parse_token()?;
+                        self.parse_and_expect_token(IS)?;
+                        return self.skip_value();
+                    }
+                }
+                self.rollback(start_index);
+                self.skip_compound()
+            }
+            Parsed::Token(LIST) | Parsed::Token(OBJECT) | Parsed::Token(EMPTY)
+                if self.peek_bare_compound()? =>
+            {
+                self.skip_compound()
+            }
+            Parsed::Token(token) if VALUE_KEYWORDS.contains(&token) || token == EMPTY => {
+                let _ = self.parse_token()?;
+                Ok(())
+            }
+            Parsed::Token(token) if self.is_number_word(token) => {
+                let _ = self.parse_number()?;
+                Ok(())
+            }
+            Parsed::Token(_) => Err(self.unexpected_token(self.index)),
+            Parsed::Number(_) | Parsed::Str(_) => {
+                let _ = self.parse_next()?;
+                Ok(())
+            }
+        }
+    }
+
+    fn visit_compound<V>(&mut self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        // TODO best way to handle "the empty `object name`"?
+        let mut compound = Compound::new(self);
+        compound.describe()?;
+        if compound.is_object() {
+            visitor.visit_map(compound)
+        } else {
+            visitor.visit_seq(compound)
+        }
+    }
+
+    fn skip_compound(&mut self) -> Result<()> {
+        let mut compound = Compound::new(self);
+        compound.describe()?;
+        if compound.is_list() {
+            while de::SeqAccess::next_element::<de::IgnoredAny>(&mut compound)?.is_some() {}
+        } else {
+            while de::MapAccess::next_key::<de::IgnoredAny>(&mut compound)?.is_some() {
+                let _ = de::MapAccess::next_value::<de::IgnoredAny>(&mut compound)?;
+            }
+        }
+        Ok(())
+    }
 }
 
-impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
     type Error = Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -181,33 +579,34 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     {
         match self.peek_next()? {
             Parsed::Token(token) => match token {
-                TRUE | FALSE | ON | OFF | ENABLED | DISABLED => self.deserialize_bool(visitor),
+                TRUE | FALSE | ON | OFF | ENABLED | DISABLED | YES | NO => {
+                    self.deserialize_bool(visitor)
+                }
+                EMPTY if self.peek_bare_compound()? => self.visit_compound(visitor),
                 EMPTY | NOTHING => self.deserialize_unit(visitor),
+                token if self.is_number_word(token) => self.deserialize_i64(visitor),
                 THE => {
                     let start_index = self.index;
                     let _ = self.parse_token()?;
-                    match self.parse_next()? {
-                        Parsed::Str(_) => {
-                            self.rollback(start_index);
-                            return self.deserialize_enum("", &[], visitor);
-                        }
-                        _ => {
-                            // TODO best way to handle "the empty `object name`"?
-                            self.rollback(start_index);
-                            let mut compound = Compound::new(self);
-                            compound.describe()?;
-                            if compound.is_object() {
-                                visitor.visit_map(compound)
-                            } else {
-                                visitor.visit_seq(compound)
-                            }
-                        }
+                    let is_variant = match self.parse_next()? {
+                        // only a newtype variant is told apart from a named list or object
+                        Parsed::Str(_) => matches!(self.peek_next(), Ok(Parsed::Token(WHICH))),
+                        _ => false,
+                    };
+                    self.rollback(start_index);
+                    if is_variant {
+                        self.deserialize_enum("", &[], visitor)
+                    } else {
+                        self.visit_compound(visitor)
                     }
                 }
-                _ => Err(Error::ExpectedKeyWord(THE)), // TODO this isn't really correct
+                LIST | OBJECT => self.visit_compound(visitor),
+                _ => Err(self.unexpected_token(self.index)),
             },
             Parsed::Number(Number::Float(_)) => self.deserialize_f64(visitor),
             Parsed::Number(Number::Integer(_)) => self.deserialize_i64(visitor),
+            Parsed::Number(Number::Integer128(_)) => self.deserialize_i128(visitor),
+            Parsed::Number(Number::Unsigned128(_)) => self.deserialize_u128(visitor),
             Parsed::Str(_) => self.deserialize_str(visitor),
         }
     }
@@ -217,8 +616,8 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
         V: de::Visitor<'de>,
     {
         match self.parse_token()? {
-            TRUE | ON | ENABLED => visitor.visit_bool(true),
-            FALSE | OFF | DISABLED => visitor.visit_bool(false),
+            TRUE | ON | ENABLED | YES => visitor.visit_bool(true),
+            FALSE | OFF | DISABLED | NO => visitor.visit_bool(false),
             _ => Err(Error::ExpectedBool),
         }
     }
@@ -237,168 +636,70 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => visitor.visit_i64(num),
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    visitor.visit_i64(num as i64)
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_i64(self.parse_signed()?)
     }
 
     fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => visitor.visit_i32(num as i32),
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    visitor.visit_i32(num as i32)
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_i32(self.parse_signed()?)
     }
 
     fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => visitor.visit_i16(num as i16),
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    visitor.visit_i16(num as i16)
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_i16(self.parse_signed()?)
     }
 
     fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => visitor.visit_i8(num as i8),
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    visitor.visit_i8(num as i8)
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_i8(self.parse_signed()?)
     }
 
     fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => {
-                if num.is_negative() {
-                    Err(Error::ExpectedUnsigned)
-                } else {
-                    visitor.visit_u64(num as u64)
-                }
-            }
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    if num.is_sign_negative() {
-                        Err(Error::ExpectedUnsigned)
-                    } else {
-                        visitor.visit_u64(num as u64)
-                    }
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_u64(self.parse_unsigned()?)
     }
 
     fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => {
-                if num.is_negative() {
-                    Err(Error::ExpectedUnsigned)
-                } else {
-                    visitor.visit_u32(num as u32)
-                }
-            }
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    if num.is_sign_negative() {
-                        Err(Error::ExpectedUnsigned)
-                    } else {
-                        visitor.visit_u32(num as u32)
-                    }
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_u32(self.parse_unsigned()?)
     }
 
     fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => {
-                if num.is_negative() {
-                    Err(Error::ExpectedUnsigned)
-                } else {
-                    visitor.visit_u16(num as u16)
-                }
-            }
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    if num.is_sign_negative() {
-                        Err(Error::ExpectedUnsigned)
-                    } else {
-                        visitor.visit_u16(num as u16)
-                    }
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_u16(self.parse_unsigned()?)
     }
 
     fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => {
-                if num.is_negative() {
-                    Err(Error::ExpectedUnsigned)
-                } else {
-                    visitor.visit_u8(num as u8)
-                }
-            }
-            Number::Float(num) => {
-                if num.trunc() == num {
-                    if num.is_sign_negative() {
-                        Err(Error::ExpectedUnsigned)
-                    } else {
-                        visitor.visit_u8(num as u8)
-                    }
-                } else {
-                    Err(Error::ExpectedInteger)
-                }
-            }
-        }
+        visitor.visit_u8(self.parse_unsigned()?)
+    }
+
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_i128(self.parse_signed()?)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_u128(self.parse_unsigned()?)
     }
 
     fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -407,6 +708,8 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     {
         match self.parse_number()? {
             Number::Integer(num) => visitor.visit_f64(num as f64),
+            Number::Integer128(num) => visitor.visit_f64(num as f64),
+            Number::Unsigned128(num) => visitor.visit_f64(num as f64),
             Number::Float(num) => visitor.visit_f64(num),
         }
     }
@@ -415,10 +718,7 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: de::Visitor<'de>,
     {
-        match self.parse_number()? {
-            Number::Integer(num) => visitor.visit_f32(num as f32),
-            Number::Float(num) => visitor.visit_f32(num as f32),
-        }
+        visitor.visit_f32(self.parse_f32()?)
     }
 
     fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -458,15 +758,10 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: de::Visitor<'de>,
     {
-        match self.peek_next()? {
-            Parsed::Token(string) => match string {
-                EMPTY | NOTHING => {
-                    let _ = self.parse_next()?;
-                    return visitor.visit_none();
-                }
-                _ => (),
-            },
-            _ => (),
+        // `empty` is a unit value so only `nothing` is none, which keeps `Some(())` apart
+        if let Parsed::Token(NOTHING) = self.peek_next()? {
+            let _ = self.parse_next()?;
+            return visitor.visit_none();
         }
         visitor.visit_some(self)
     }
@@ -537,17 +832,14 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: de::Visitor<'de>,
     {
-        match unescape_str(self.parse_string()?) {
-            Cow::Borrowed(string) => visitor.visit_borrowed_bytes(string.as_ref()),
-            Cow::Owned(string) => visitor.visit_byte_buf(string.into_bytes()),
-        }
+        self.deserialize_byte_buf(visitor)
     }
 
     fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_byte_buf(unescape_str(self.parse_string()?).to_string().into_bytes())
+        visitor.visit_byte_buf(decode_bytes(self.parse_string()?)?)
     }
 
     fn deserialize_struct<V>(
@@ -574,12 +866,16 @@ impl<'a, 'de> de::Deserializer<'de> for &'a mut Deserializer<'de> {
         match self.peek_next()? {
             Parsed::Token(THE) => visitor.visit_enum(VariantAccess::new(self, variants)),
             Parsed::Str(_) => visitor.visit_enum(UnitVariantAccess::new(self, variants)),
-            _ => Err(Error::ExpectedKeyWord(THE)), // TODO not correct, could also expect a string
+            _ => Err(self.unexpected_token(self.index)),
         }
     }
 
-    serde::forward_to_deserialize_any! {
-        ignored_any
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.skip_value()?;
+        visitor.visit_unit()
     }
 }
 
@@ -616,12 +912,16 @@ impl<'a, 'de> de::Deserializer<'de> for MapKey<'a, 'de> {
     {
         match self.de.peek_next()? {
             Parsed::Token(token) => match token {
-                TRUE | FALSE | ON | OFF | ENABLED | DISABLED => self.deserialize_bool(visitor),
+                TRUE | FALSE | ON | OFF | ENABLED | DISABLED | YES | NO => {
+                    self.deserialize_bool(visitor)
+                }
                 EMPTY | NOTHING => self.deserialize_unit(visitor),
                 _ => Err(Error::ExpectedPrimitiveMapKey),
             },
             Parsed::Number(Number::Float(_)) => self.deserialize_f64(visitor),
             Parsed::Number(Number::Integer(_)) => self.deserialize_i64(visitor),
+            Parsed::Number(Number::Integer128(_)) => self.deserialize_i128(visitor),
+            Parsed::Number(Number::Unsigned128(_)) => self.deserialize_u128(visitor),
             Parsed::Str(_) => self.deserialize_str(visitor),
         }
     }
@@ -629,8 +929,9 @@ impl<'a, 'de> de::Deserializer<'de> for MapKey<'a, 'de> {
     // TODO deserialize_enum handling for unit enum case
 
     forward_to_internal_de!(
-        deserialize_bool deserialize_i64 deserialize_i32 deserialize_i16 deserialize_i8
-        deserialize_u64 deserialize_u32 deserialize_u16 deserialize_u8 deserialize_f32 deserialize_f64
+        deserialize_bool deserialize_i128 deserialize_i64 deserialize_i32 deserialize_i16
+        deserialize_i8 deserialize_u128 deserialize_u64 deserialize_u32 deserialize_u16
+        deserialize_u8 deserialize_f32 deserialize_f64
         deserialize_char deserialize_str deserialize_string deserialize_unit deserialize_option
         deserialize_bytes deserialize_byte_buf
     );
@@ -657,10 +958,10 @@ impl<'a, 'de> de::Deserializer<'de> for MapExpectedKey<'a, 'de> {
     where
         V: de::Visitor<'de>,
     {
-        let string = self.de.parse_string()?;
-        match dehumanize_match(&unescape_str(string), self.expected_keys) {
+        let string = unescape_str(self.de.parse_string()?);
+        match dehumanize_match(&string, self.expected_keys) {
             Some(string) => visitor.visit_borrowed_str(string),
-            None => visitor.visit_string((self.default_dehumanize)(string)),
+            None => visitor.visit_string((self.default_dehumanize)(&string)),
         }
     }
 
@@ -694,7 +995,11 @@ struct Compound<'a, 'de> {
     kind: Option<CompoundKind>,
     is_empty: bool,
     first: bool,
+    len: usize,
     expected_keys: Option<&'static [&'static str]>,
+    is_nested: bool,
+    is_top_level: bool,
+    seen_keys: BTreeSet<String>,
 }
 
 impl<'a, 'de> Compound<'a, 'de> {
@@ -706,7 +1011,11 @@ impl<'a, 'de> Compound<'a, 'de> {
             kind: None,
             is_empty: false,
             first: true,
+            len: 0,
             expected_keys: None,
+            is_nested: false,
+            is_top_level: false,
+            seen_keys: BTreeSet::new(),
         }
     }
 
@@ -721,7 +1030,11 @@ impl<'a, 'de> Compound<'a, 'de> {
             kind: None,
             is_empty: false,
             first: true,
+            len: 0,
             expected_keys: Some(expected_keys),
+            is_nested: false,
+            is_top_level: false,
+            seen_keys: BTreeSet::new(),
         }
     }
 
@@ -738,15 +1051,23 @@ impl<'a, 'de> Compound<'a, 'de> {
     }
 
     fn describe(&mut self) -> Result<()> {
-        if self.de.parse_token()? != THE {
-            return Err(Error::ExpectedKeyWord(THE));
-        }
-        match self.de.peek_next()? {
-            Parsed::Token(EMPTY) => {
-                let _ = self.de.parse_token()?;
-                self.is_empty = true;
+        if !self.is_nested {
+            if self.de.depth >= self.de.max_depth {
+                return Err(Error::DepthLimitExceeded);
             }
-            _ => (),
+            self.de.depth += 1;
+            self.is_nested = true;
+            self.is_top_level = self.de.depth == 1;
+        }
+        // the `the` may be left out before `list` and `object` but not before a name
+        if self.de.peek_next()? == Parsed::Token(THE) {
+            let _ = self.de.parse_token()?;
+        } else if !self.de.peek_bare_compound()? {
+            return Err(Error::ExpectedKeyWord(self.de.localize(THE)));
+        }
+        if let Parsed::Token(EMPTY) = self.de.peek_next()? {
+            let _ = self.de.parse_token()?;
+            self.is_empty = true;
         }
         match self.de.parse_next()? {
             Parsed::Token(token) => match token {
@@ -761,29 +1082,30 @@ impl<'a, 'de> Compound<'a, 'de> {
             Parsed::Str(name) => self.name = Some(name),
             _ => return Err(Error::ExpectedObjectDescriptor),
         };
-        match self.de.peek_next() {
-            Ok(Parsed::Token(HENCEFORTH)) => {
-                self.de.parse_next()?;
-                match self.de.parse_next()? {
-                    Parsed::Str(string) => self.scope = Some(string),
-                    _ => return Err(Error::ExpectedString),
-                }
+        if let Ok(Parsed::Token(HENCEFORTH)) = self.de.peek_next() {
+            self.de.parse_next()?;
+            match self.de.parse_next()? {
+                Parsed::Str(string) => self.scope = Some(string),
+                _ => return Err(Error::ExpectedString),
             }
-            _ => (),
         }
-        if !self.is_empty {
-            match self.de.parse_token()? {
-                WHERE => (),
-                _ => return Err(Error::ExpectedKeyWord(WHERE)),
+        if self.is_empty {
+            // an empty compound may be named and scoped but can't have entries
+            if let Ok(Parsed::Token(WHERE)) = self.de.peek_next() {
+                return Err(Error::DeclaredEmptyWithEntries);
             }
+        } else {
+            self.de.parse_and_expect_token(WHERE)?;
             if self.kind.is_none() {
                 match self.de.peek_next()? {
                     Parsed::Token(token) => match token {
-                        AN => self.kind = Some(CompoundKind::List),
-                        THE | TRUE | FALSE | ON | OFF | ENABLED | DISABLED | EMPTY | NOTHING => {
-                            self.kind = Some(CompoundKind::Object)
+                        A | AN => self.kind = Some(CompoundKind::List),
+                        THE if self.de.peek_the_ordinal()?.is_some() => {
+                            self.kind = Some(CompoundKind::List)
                         }
-                        _ => return Err(Error::ExpectedKeyWord(THE)), // TODO this isnt really correct. it could be multiple tokens
+                        THE | TRUE | FALSE | ON | OFF | ENABLED | DISABLED | YES | NO | EMPTY
+                        | NOTHING => self.kind = Some(CompoundKind::Object),
+                        _ => return Err(self.de.unexpected_token(self.de.index)),
                     },
                     Parsed::Str(_) => self.kind = Some(CompoundKind::Object),
                     Parsed::Number(_) => self.kind = Some(CompoundKind::Object),
@@ -794,6 +1116,14 @@ impl<'a, 'de> Compound<'a, 'de> {
     }
 }
 
+impl<'a, 'de> Drop for Compound<'a, 'de> {
+    fn drop(&mut self) {
+        if self.is_nested {
+            self.de.depth -= 1;
+        }
+    }
+}
+
 impl<'a, 'de> de::SeqAccess<'de> for Compound<'a, 'de> {
     type Error = Error;
 
@@ -812,49 +1142,73 @@ impl<'a, 'de> de::SeqAccess<'de> for Compound<'a, 'de> {
         }
 
         let start_index = self.de.index;
+        // the position and index of an ordinal such as `the second item` used instead of
+        // `another item`
+        let mut ordinal = None;
 
         if self.first {
-            self.de.parse_and_expect_token(AN)?;
+            let article = self.de.item_article();
+            if let Some(position) = self.de.peek_the_ordinal()? {
+                ordinal = Some((position, self.de.parse_the_ordinal()?));
+            } else {
+                self.de.parse_and_expect_token(article)?;
+            }
+        } else if self.de.peek_terminal_period() {
+            return Ok(None);
         } else {
             match self.de.parse_and_expect_token(AND) {
                 Ok(()) => {
-                    match self.de.parse_next()? {
-                        Parsed::Token(ANOTHER) => (),
-                        Parsed::Str(_) | Parsed::Token(THE) => {
-                            // possible key from a higher scope map
-                            // TODO check if top level and throw error if scope not found
-                            self.de.rollback(start_index);
-                            return Ok(None);
+                    if let Some(position) = self.de.peek_the_ordinal()? {
+                        ordinal = Some((position, self.de.parse_the_ordinal()?));
+                    } else {
+                        let next_index = self.de.index;
+                        match self.de.parse_next()? {
+                            Parsed::Token(ANOTHER) => (),
+                            Parsed::Str(_) | Parsed::Token(THE) => {
+                                // possible key from a higher scope map
+                                if self.is_top_level {
+                                    return Err(self.de.unexpected_token(next_index));
+                                }
+                                self.de.rollback(start_index);
+                                return Ok(None);
+                            }
+                            _ => return Err(Error::ExpectedKeyWord(self.de.localize(ANOTHER))),
                         }
-                        _ => return Err(Error::ExpectedKeyWord(ANOTHER)),
                     }
                 }
                 Err(Error::Parse(ParseError::UnexpectedEof)) => return Ok(None),
                 Err(err) => return Err(err),
             }
         }
-        self.de.parse_and_expect_token(ITEM)?;
-
-        // TODO check if top level and throw error if scope not found
-        match self.de.peek_next()? {
-            Parsed::Token(OF) => {
-                let _ = self.de.parse_token()?;
-                let scope = self.de.parse_string()?;
-                if self.scope != Some(scope) {
-                    if self.first {
-                        return Err(Error::ShouldBeDeclaredEmpty);
-                    }
-                    self.de.rollback(start_index);
-                    return Ok(None);
+        self.de.parse_and_expect_token(self.de.keywords.item)?;
+
+        if let Parsed::Token(OF) = self.de.peek_next()? {
+            let _ = self.de.parse_token()?;
+            let scope = self.de.parse_string()?;
+            if self.scope != Some(scope) {
+                // there is no higher scope for the entry to belong to
+                if self.is_top_level {
+                    return Err(Error::UnknownScope(unescape_str(scope).into_owned()));
+                }
+                if self.first {
+                    return Err(Error::ShouldBeDeclaredEmpty);
                 }
+                self.de.rollback(start_index);
+                return Ok(None);
+            }
+        }
+
+        if let Some((position, index)) = ordinal {
+            if position != self.len + 1 {
+                return Err(self.de.unexpected_token(index));
             }
-            _ => (),
         }
 
         self.de.parse_and_expect_token(IS)?;
 
         let res = seed.deserialize(&mut *self.de)?;
         self.first = false;
+        self.len += 1;
         Ok(Some(res))
     }
 }
@@ -879,6 +1233,9 @@ impl<'a, 'de> de::MapAccess<'de> for Compound<'a, 'de> {
         let start_index = self.de.index;
 
         if !self.first {
+            if self.de.peek_terminal_period() {
+                return Ok(None);
+            }
             match self.de.parse_and_expect_token(AND) {
                 Ok(_) => (),
                 Err(Error::Parse(ParseError::UnexpectedEof)) => return Ok(None),
@@ -887,12 +1244,14 @@ impl<'a, 'de> de::MapAccess<'de> for Compound<'a, 'de> {
         }
 
         match self.de.peek_next()? {
-            Parsed::Token(THE) => {
+            Parsed::Token(THE) if self.de.peek_the_ordinal()?.is_none() => {
                 let _ = self.de.parse_token()?;
             }
-            Parsed::Token(ANOTHER) => {
+            Parsed::Token(ANOTHER) | Parsed::Token(THE) => {
                 // possible item from a higher scope list
-                // TODO check if top level and throw error if scope not found
+                if self.is_top_level {
+                    return Err(self.de.unexpected_token(self.de.index));
+                }
                 self.de.rollback(start_index);
                 return Ok(None);
             }
@@ -902,21 +1261,38 @@ impl<'a, 'de> de::MapAccess<'de> for Compound<'a, 'de> {
         let mut de_copy = self.de.clone();
 
         let _ = serde::de::IgnoredAny::deserialize(MapKey { de: &mut *self.de })?;
-
-        // TODO check if top level and throw error if scope not found
-        match self.de.peek_next()? {
-            Parsed::Token(OF) => {
-                let _ = self.de.parse_token()?;
-                let scope = self.de.parse_string()?;
-                if self.scope != Some(scope) {
-                    if self.first {
-                        return Err(Error::ShouldBeDeclaredEmpty);
-                    }
-                    self.de.rollback(start_index);
-                    return Ok(None);
+        let key = self.de.src[de_copy.index..self.de.index].trim();
+
+        if let Parsed::Token(OF) = self.de.peek_next()? {
+            let _ = self.de.parse_token()?;
+            let scope = self.de.parse_string()?;
+            if self.scope != Some(scope) {
+                // there is no higher scope for the entry to belong to
+                if self.is_top_level {
+                    return Err(Error::UnknownScope(unescape_str(scope).into_owned()));
                 }
+                if self.first {
+                    return Err(Error::ShouldBeDeclaredEmpty);
+                }
+                self.de.rollback(start_index);
+                return Ok(None);
+            }
+        }
+
+        if self.de.deny_duplicate_keys {
+            let key = match de_copy.clone().parse_next()? {
+                Parsed::Str(string) => unescape_str(string).into_owned(),
+                _ => key.to_string(),
+            };
+            // compare keys by the field they name rather than by how they are written
+            let normalized = match self.expected_keys {
+                Some(expected_keys) => dehumanize_match(&key, expected_keys)
+                    .map_or_else(|| dehumanize_snake(&key), ToString::to_string),
+                None => key.clone(),
+            };
+            if !self.seen_keys.insert(normalized) {
+                return Err(Error::DuplicateKey(key));
             }
-            _ => (),
         }
 
         self.de.parse_and_expect_token(IS)?;
@@ -1076,11 +1452,11 @@ mod tests {
     use super::*;
     use crate::helpers::*;
     use alloc::collections::BTreeMap;
-    use alloc::vec::Vec;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
     use serde_json::{json, Value};
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn deserialize_bool() -> Result<()> {
         assert_eq!(true, from_str::<bool>("true")?);
         assert_eq!(false, from_str::<bool>("false")?);
@@ -1088,6 +1464,8 @@ mod tests {
         assert_eq!(false, from_str::<bool>("off")?);
         assert_eq!(true, from_str::<bool>("enabled")?);
         assert_eq!(false, from_str::<bool>("disabled")?);
+        assert_eq!(true, from_str::<bool>("yes")?);
+        assert_eq!(false, from_str::<bool>("no")?);
 
         assert_eq!(json!(true), from_str::<Value>("true")?);
         assert_eq!(json!(false), from_str::<Value>("false")?);
@@ -1095,6 +1473,14 @@ mod tests {
         assert_eq!(json!(false), from_str::<Value>("off")?);
         assert_eq!(json!(true), from_str::<Value>("enabled")?);
         assert_eq!(json!(false), from_str::<Value>("disabled")?);
+        assert_eq!(json!(true), from_str::<Value>("yes")?);
+        assert_eq!(json!(false), from_str::<Value>("no")?);
+        assert_eq!(
+            json!({"done": [true, false]}),
+            from_str::<Value>(
+                "the object where the `done` is the list where an item is yes and another item is no"
+            )?
+        );
 
         Ok(())
     }
@@ -1141,6 +1527,78 @@ mod tests {
         assert_eq!(0, from_str::<i8>("0")?);
         assert_eq!(0., from_str::<f64>("0")?);
         assert_eq!(0., from_str::<f32>("0")?);
+        // parsed straight into an f32 rather than rounded through an f64
+        assert_eq!(
+            1. + f32::EPSILON,
+            from_str::<f32>("1.00000005960464477550")?
+        );
+        assert_eq!(1_000.25, from_str::<f32>("1_000.25")?);
+        assert_eq!(1e6, from_str::<f64>("1e6")?);
+        assert_eq!(-2500, from_str::<i64>("-2.5E3")?);
+        assert_eq!(1_000_000, from_str::<u32>("1_000_000")?);
+
+        assert_eq!(
+            u128::MAX,
+            from_str::<u128>("340282366920938463463374607431768211455")?
+        );
+        assert_eq!(
+            i128::MIN,
+            from_str::<i128>("-170141183460469231731687303715884105728")?
+        );
+        assert_eq!(1, from_str::<i128>("1")?);
+        assert_eq!(1, from_str::<u128>("1")?);
+        assert!(from_str::<u128>("-1").is_err());
+        assert_eq!(u64::MAX, from_str::<u64>("18446744073709551615")?);
+        assert!(matches!(
+            from_str::<u64>("18446744073709551616"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<i64>("9223372036854775808"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<u8>("256"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<u8>("300"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<i8>("200"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<i8>("-129"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<i32>("1e10"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<u16>("-1"),
+            Err(Error::ExpectedUnsigned)
+        ));
+        assert!(matches!(
+            from_str::<u32>("-2e3"),
+            Err(Error::ExpectedUnsigned)
+        ));
+        assert!(matches!(from_str::<u8>("1.5"), Err(Error::ExpectedInteger)));
+        assert!(matches!(
+            from_str::<i128>("1e40"),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert!(matches!(
+            from_str::<i128>(&crate::to_string(&u128::MAX)?),
+            Err(Error::NumberOutOfRange)
+        ));
+        assert_eq!(u8::MAX, from_str::<u8>("255")?);
+        assert_eq!(i8::MIN, from_str::<i8>("-128")?);
+        assert_eq!(200, from_str::<u8>("2e2")?);
+        assert_eq!(u128::MAX, from_str::<u128>(&crate::to_string(&u128::MAX)?)?);
+        assert_eq!(i128::MIN, from_str::<i128>(&crate::to_string(&i128::MIN)?)?);
 
         assert_eq!(json!(1), from_str::<Value>("1")?);
         assert_eq!(json!(1.2), from_str::<Value>("1.2")?);
@@ -1151,17 +1609,87 @@ mod tests {
     }
 
     #[test]
-    fn deserialize_str() -> Result<()> {
-        assert_eq!("hello", from_str::<String>("`hello`")?);
+    fn deserialize_number_words() -> Result<()> {
+        fn from_words<'de, T: Deserialize<'de>>(s: &'de str) -> Result<T> {
+            T::deserialize(&mut Deserializer::from_str(s).with_number_words(true))
+        }
+
+        assert_eq!(5, from_words::<u8>("five")?);
+        assert_eq!(21, from_words::<i64>("twenty one")?);
+        assert_eq!(21, from_words::<i32>("twenty-one")?);
+        assert_eq!(100, from_words::<u16>("one hundred")?);
+        assert_eq!(5.0, from_words::<f64>("Five")?);
         assert_eq!(
-            "escaped`string",
+            json!(1_121),
+            from_words::<Value>("one thousand one hundred twenty-one")?
+        );
+        assert!(matches!(
+            from_words::<u8>("three hundred"),
+            Err(Error::NumberOutOfRange)
+        ));
+        match from_words::<u8>("five five") {
+            Err(Error::UnexpectedToken(token)) => assert_eq!(token, "five five"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        assert_eq!(
+            vec![2, 30, 7],
+            from_words::<Vec<u8>>(
+                "the list where an item is two and another item is thirty and another item is 7"
+            )?
+        );
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Order {
+            count: u32,
+            name: String,
+        }
+        assert_eq!(
+            Order {
+                count: 12,
+                name: "pens".to_string()
+            },
+            from_words("the `order` where the `count` is twelve and the `name` is `pens`")?
+        );
+
+        // number words are opt in
+        assert!(from_str::<u8>("five").is_err());
+        assert!(matches!(
+            from_str::<Value>("five"),
+            Err(Error::UnexpectedToken(_))
+        ));
+        let mut case_sensitive = Deserializer::from_str("Five")
+            .with_number_words(true)
+            .with_case_sensitive(true);
+        assert!(u8::deserialize(&mut case_sensitive).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_str() -> Result<()> {
+        assert_eq!("hello", from_str::<String>("`hello`")?);
+        assert_eq!(
+            "escaped`string",
             from_str::<String>(r#"`escaped\`string`"#)?
         );
 
+        assert_eq!(r"C:\path", from_str::<String>(r"`C:\\path`")?);
+        assert_eq!(r"a\`b", from_str::<String>(r"`a\\\`b`")?);
+        assert_eq!(r"ends with \", from_str::<String>(r"`ends with \\`")?);
+
         assert_eq!("hello", from_str::<&str>("`hello`")?);
         assert!(from_str::<&str>(r#"`escaped\`string`"#).is_err());
 
         assert_eq!(json!("hello"), from_str::<Value>("`hello`")?);
+
+        let mut deserializer = Deserializer::from_str(r"'don\'t `quote`'").with_delimiter('\'');
+        assert_eq!("don't `quote`", String::deserialize(&mut deserializer)?);
+
+        let mut deserializer =
+            Deserializer::from_str(r#"the object where "key" is "value""#).with_delimiter('"');
+        assert_eq!(
+            json!({"key": "value"}),
+            Value::deserialize(&mut deserializer)?
+        );
         Ok(())
     }
 
@@ -1172,16 +1700,110 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn deserialize_bytes() -> Result<()> {
+        #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+        struct Data {
+            #[serde(with = "serde_bytes")]
+            data: Vec<u8>,
+        }
+
+        assert_eq!(
+            vec![0x68, 0x69],
+            from_str::<serde_bytes::ByteBuf>("`6869`")?.into_vec()
+        );
+        assert_eq!(
+            vec![0xab, 0xcd],
+            from_str::<serde_bytes::ByteBuf>("`ABcd`")?.into_vec()
+        );
+        assert!(from_str::<serde_bytes::ByteBuf>("`abc`").is_err());
+        assert!(from_str::<serde_bytes::ByteBuf>("`zz`").is_err());
+
+        let data = Data {
+            data: vec![0x00, 0x9f, 0x92, 0x96, 0xff, 0xfe],
+        };
+        assert_eq!(data, from_str::<Data>(&to_string(&data)?)?);
+        Ok(())
+    }
+
     #[test]
     fn deserialize_option() -> Result<()> {
         assert_eq!(Some("hello"), from_str::<Option<&str>>("`hello`")?);
         assert_eq!(Some(123), from_str::<Option<i64>>("123")?);
         assert_eq!(Some(123.123), from_str::<Option<f64>>("123.123")?);
-        assert_eq!(None, from_str::<Option<i64>>("empty")?);
         assert_eq!(None, from_str::<Option<i64>>("nothing")?);
+        assert!(from_str::<Option<i64>>("empty").is_err());
+
+        assert_eq!(Some(()), from_str::<Option<()>>("empty")?);
+        assert_eq!(None, from_str::<Option<()>>("nothing")?);
+        for value in &[Some(()), None] {
+            assert_eq!(*value, from_str::<Option<()>>(&to_string(value)?)?);
+        }
+        assert_eq!(
+            vec![Some(()), None],
+            from_str::<Vec<Option<()>>>(&to_string(&vec![Some(()), None])?)?
+        );
+
+        // an empty compound is still some value
+        assert_eq!(
+            Some(vec![]),
+            from_str::<Option<Vec<i32>>>("the empty list")?
+        );
+        let values: [Option<Vec<i32>>; 3] = [Some(vec![]), Some(vec![1]), None];
+        for value in &values {
+            assert_eq!(*value, from_str::<Option<Vec<i32>>>(&to_string(value)?)?);
+        }
+        let nested = vec![
+            Some(vec![]),
+            None,
+            Some(vec![BTreeMap::<String, u8>::new()]),
+        ];
+        assert_eq!(nested, from_str::<Vec<_>>(&to_string(&nested)?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_partial() -> Result<()> {
+        let (value, rest) = from_str_partial::<bool>("true false")?;
+        assert!(value);
+        assert_eq!(rest, "false");
+        let (value, rest) = from_str_partial::<bool>(rest)?;
+        assert!(!value);
+        assert_eq!(rest, "");
+
+        let (first, rest) = from_str_partial::<u8>("1 the list where an item is 2")?;
+        assert_eq!(first, 1);
+        assert_eq!(rest, "the list where an item is 2");
+        let (second, rest) = from_str_partial::<Vec<u8>>(rest)?;
+        assert_eq!(second, vec![2]);
+        assert_eq!(rest, "");
+
+        let mut deserializer = Deserializer::from_str("`string` 1");
+        assert_eq!(deserializer.index(), 0);
+        assert_eq!(String::deserialize(&mut deserializer)?, "string");
+        assert_eq!(deserializer.index(), 9);
+        assert_eq!(deserializer.rest(), "1");
         Ok(())
     }
 
+    #[test]
+    fn syntax_error_position() {
+        let src = "the list\nwhere an item is `a`\nand another item is `b`c";
+        let err = from_str::<Vec<String>>(src).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: expected whitespace at line 3, column 24"
+        );
+        assert!(matches!(
+            err,
+            Error::Syntax {
+                error: ParseError::ExpectedWhitespace(53),
+                line: 3,
+                column: 24,
+            }
+        ));
+    }
+
     #[test]
     fn deserialize_list() -> Result<()> {
         assert_eq!(Vec::<i64>::new(), from_str::<Vec<i64>>("the empty list")?);
@@ -1215,6 +1837,568 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dehumanize_candidates() {
+        const CANDIDATES: [&str; 3] = ["id", "public_key", "NewUser"];
+        let matched = dehumanize_match("id", &CANDIDATES).unwrap();
+        assert!(core::ptr::eq(matched, CANDIDATES[0]));
+        let matched = dehumanize_match("public key", &CANDIDATES).unwrap();
+        assert!(core::ptr::eq(matched, CANDIDATES[1]));
+        let matched = dehumanize_match("new  user", &CANDIDATES).unwrap();
+        assert!(core::ptr::eq(matched, CANDIDATES[2]));
+        assert_eq!(dehumanize_match("public", &CANDIDATES), None);
+        assert_eq!(dehumanize_match("public key id", &CANDIDATES), None);
+        assert_eq!(dehumanize_match("", &CANDIDATES), None);
+    }
+
+    #[test]
+    fn deserialize_renamed_fields() -> Result<()> {
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct Renamed {
+            #[serde(rename = "user name")]
+            name: u8,
+            #[serde(rename = "userID")]
+            id: u8,
+            #[serde(rename = "lastName")]
+            last_name: u8,
+            #[serde(rename = "Is_Done")]
+            done: u8,
+            #[serde(rename = "x-y")]
+            xy: u8,
+            #[serde(rename = "URL")]
+            url: u8,
+        }
+
+        let renamed = Renamed {
+            name: 1,
+            id: 2,
+            last_name: 3,
+            done: 4,
+            xy: 5,
+            url: 6,
+        };
+        let string = to_string(&renamed)?;
+        assert_eq!(
+            string,
+            "the `renamed` where the `user name` is 1 and the `user ID` is 2 and the `last name` \
+             is 3 and `is done` is 4 and the `x-y` is 5 and the `URL` is 6"
+        );
+        assert_eq!(renamed, from_str(&string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_missing_fields() -> Result<()> {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Settings {
+            name: String,
+            #[serde(default)]
+            retries: u8,
+            #[serde(default = "default_port")]
+            port: u16,
+            label: Option<String>,
+        }
+
+        fn default_port() -> u16 {
+            80
+        }
+
+        assert_eq!(
+            Settings {
+                name: "web".to_string(),
+                retries: 0,
+                port: 80,
+                label: None,
+            },
+            from_str("the `settings` where the `name` is `web`")?
+        );
+        assert_eq!(
+            Settings {
+                name: "web".to_string(),
+                retries: 0,
+                port: 8080,
+                label: Some("main".to_string()),
+            },
+            from_str("the `settings` where the `port` is 8080 and the `label` is `main` and the `name` is `web`")?
+        );
+
+        #[derive(Deserialize, Debug, PartialEq, Default)]
+        #[serde(default)]
+        struct AllDefault {
+            retries: u8,
+            verbose: bool,
+        }
+
+        assert_eq!(AllDefault::default(), from_str("the empty `all default`")?);
+        assert_eq!(
+            AllDefault {
+                retries: 0,
+                verbose: true,
+            },
+            from_str("the `all default` where the `verbose` is true")?
+        );
+
+        // required fields are still required
+        assert_eq!(
+            from_str::<Settings>("the `settings` where the `port` is 8080")
+                .unwrap_err()
+                .to_string(),
+            "custom: missing field `name`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_ordinal_items() -> Result<()> {
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct Pair(u8, String);
+
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct Nested {
+            lists: Vec<Vec<u8>>,
+            pair: Pair,
+        }
+
+        assert_eq!(
+            vec![1, 2],
+            from_str::<Vec<u8>>("the list where the first item is 1 and the second item is 2")?
+        );
+        assert_eq!(
+            vec![1, 2, 3],
+            from_str::<Vec<u8>>(
+                "the list where an item is 1 and the 2nd item is 2 and another item is 3"
+            )?
+        );
+        assert_eq!(
+            Pair(4, "four".to_string()),
+            from_str::<Pair>("the `pair` where The First item is 4 and the 2ND item is `four`")?
+        );
+        assert!(matches!(
+            from_str::<Vec<u8>>("the list where the second item is 1"),
+            Err(Error::UnexpectedToken(token)) if token == "second"
+        ));
+
+        let value = Nested {
+            lists: vec![vec![1, 2], vec![], vec![3]],
+            pair: Pair(4, "four".to_string()),
+        };
+        let builder = crate::SerializerBuilder::new().ordinal_items(true);
+        let mut string = String::new();
+        crate::to_writer_with(&mut string, &value, &builder)?;
+        assert_eq!(string, "the `nested` henceforth `the nested` where the `lists` is the list henceforth `the nested lists list` where the first item is the list where the first item is 1 and the second item is 2 and the second item of `the nested lists list` is the empty list and the third item is the list where the first item is 3 and the `pair` of `the nested` is the `pair` where the first item is 4 and the second item is `four`");
+        assert_eq!(value, from_str::<Nested>(&string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_empty_scoped() -> Result<()> {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Named {}
+
+        assert_eq!(
+            Vec::<u8>::new(),
+            from_str::<Vec<u8>>("the empty `named list` henceforth `alias`")?
+        );
+        assert_eq!(
+            Vec::<u8>::new(),
+            from_str::<Vec<u8>>("the empty list henceforth `x`")?
+        );
+        // without a type to guide it an empty named compound is read as an object
+        assert_eq!(
+            json!({}),
+            from_str::<Value>("the empty `named list` henceforth `alias`")?
+        );
+        assert_eq!(
+            Named {},
+            from_str::<Named>("the empty `named` henceforth `alias`")?
+        );
+        assert_eq!(
+            vec![vec![1], vec![]],
+            from_str::<Vec<Vec<u8>>>("the list henceforth `the list` where an item is the list where an item is 1 and another item of `the list` is the empty `named list` henceforth `alias`")?
+        );
+
+        assert!(matches!(
+            from_str::<Vec<u8>>("the empty `named list` henceforth `alias` where an item is 1"),
+            Err(Error::DeclaredEmptyWithEntries)
+        ));
+        assert!(matches!(
+            from_str::<Value>("the empty list where an item is 1"),
+            Err(Error::DeclaredEmptyWithEntries)
+        ));
+        assert!(matches!(
+            from_str::<BTreeMap<String, u8>>("the empty object where `key` is 1"),
+            Err(Error::DeclaredEmptyWithEntries)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn unexpected_tokens() {
+        #[derive(Deserialize, Debug)]
+        enum Enum {
+            Variant,
+        }
+
+        let unexpected = |res: Result<Value>| match res {
+            Err(Error::UnexpectedToken(token)) => token,
+            res => panic!("unexpected result {:?}", res),
+        };
+        assert_eq!(unexpected(from_str("banana")), "banana");
+        assert_eq!(unexpected(from_str("café au lait")), "café");
+        match from_str::<Vec<u8>>("the `thing` where banana is 1") {
+            Err(Error::UnexpectedToken(token)) => assert_eq!(token, "banana"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        assert_eq!(
+            unexpected(from_str("the list where an item is Banana")),
+            "Banana"
+        );
+        assert_eq!(
+            from_str::<Value>("banana").unwrap_err().to_string(),
+            "unexpected token 'banana'"
+        );
+        match from_str::<Enum>("banana") {
+            Err(Error::UnexpectedToken(token)) => assert_eq!(token, "banana"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        match from_str::<Vec<u8>>("a list where an item is 1") {
+            Err(Error::ExpectedKeyWord(keyword)) => assert_eq!(keyword, "the"),
+            res => panic!("unexpected result {:?}", res),
+        }
+    }
+
+    #[test]
+    fn duplicate_keys() -> Result<()> {
+        let strict = |s| {
+            BTreeMap::<String, u8>::deserialize(
+                &mut Deserializer::from_str(s).with_deny_duplicate_keys(true),
+            )
+        };
+        let src = "the object where `a` is 1 and `a` is 2";
+
+        // lenient by default where the last value wins
+        assert_eq!(
+            vec![("a".to_string(), 2)]
+                .into_iter()
+                .collect::<BTreeMap<_, _>>(),
+            from_str::<BTreeMap<String, u8>>(src)?
+        );
+        match strict(src) {
+            Err(Error::DuplicateKey(key)) => assert_eq!(key, "a"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        assert_eq!(
+            strict("the object where the `a` is 1 and the `b` is 2 and `a` is 3")
+                .unwrap_err()
+                .to_string(),
+            "duplicate key 'a'"
+        );
+        assert!(BTreeMap::<u8, u8>::deserialize(
+            &mut Deserializer::from_str("the object where 1 is 1 and 1 is 2")
+                .with_deny_duplicate_keys(true)
+        )
+        .is_err());
+
+        // keys are only compared within the same object
+        assert_eq!(
+            json!({"a": {"a": 1}, "b": {"a": 2}}),
+            Value::deserialize(
+                &mut Deserializer::from_str(
+                    "the object henceforth `the object` where the `a` is the object where the `a` \
+                     is 1 and the `b` of `the object` is the object where the `a` is 2"
+                )
+                .with_deny_duplicate_keys(true)
+            )?
+        );
+
+        // keys written differently are duplicates when they name the same key
+        match strict(r"the object where `ab` is 1 and `a\b` is 2") {
+            Err(Error::DuplicateKey(key)) => assert_eq!(key, "ab"),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct User {
+            user_name: String,
+        }
+        match User::deserialize(
+            &mut Deserializer::from_str(
+                "the object where the `user name` is `a` and the `user_name` is `b`",
+            )
+            .with_deny_duplicate_keys(true),
+        ) {
+            Err(Error::DuplicateKey(key)) => assert_eq!(key, "user_name"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn empty_input() {
+        for src in &["", "   ", "\n\t "] {
+            assert!(matches!(from_str::<u8>(src), Err(Error::EmptyInput)));
+            assert!(matches!(from_str::<()>(src), Err(Error::EmptyInput)));
+            assert!(matches!(
+                from_str::<Option<u8>>(src),
+                Err(Error::EmptyInput)
+            ));
+            assert!(matches!(from_str::<Value>(src), Err(Error::EmptyInput)));
+            assert!(matches!(from_str::<Vec<u8>>(src), Err(Error::EmptyInput)));
+        }
+        assert_eq!(
+            from_str::<String>(" ").unwrap_err().to_string(),
+            "empty document"
+        );
+        // input which ends early is still an unexpected end
+        assert!(matches!(
+            from_str::<Vec<u8>>("the list where"),
+            Err(Error::Parse(ParseError::UnexpectedEof))
+        ));
+    }
+
+    #[test]
+    fn terminal_period() -> Result<()> {
+        assert_eq!(
+            vec![1, 2],
+            from_str::<Vec<u8>>("the list where an item is 1 and another item is 2.")?
+        );
+        assert_eq!(
+            json!({"a": true, "b": "end."}),
+            from_str::<Value>("The object where the `a` is true and the `b` is `end.`. ")?
+        );
+        assert_eq!(json!(1), from_str::<Value>("1.")?);
+        assert_eq!(1.5, from_str::<f64>("1.5.")?);
+        // only a single period is dropped
+        assert!(from_str::<Vec<String>>("the list where an item is `a`..").is_err());
+        // the period ends the sentence and so the list
+        assert_eq!(
+            (vec!["a".to_string()], "and another item is `b`"),
+            from_str_partial::<Vec<String>>(
+                "the list where an item is `a`. and another item is `b`"
+            )?
+        );
+
+        // sentences can be read one after another
+        let src = "the list where an item is `a`. the list where an item is `b`.";
+        let (first, rest) = from_str_partial::<Vec<String>>(src)?;
+        assert_eq!(
+            (vec!["a".to_string()], "the list where an item is `b`."),
+            (first, rest)
+        );
+        let (second, rest) = from_str_partial::<Vec<String>>(rest)?;
+        assert_eq!((vec!["b".to_string()], ""), (second, rest));
+        let (num, rest) = from_str_partial::<u8>("1. 2.")?;
+        assert_eq!((1, "2."), (num, rest));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_terse() -> Result<()> {
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct User {
+            id: u8,
+            is_admin: bool,
+            tags: Vec<String>,
+            groups: Vec<Vec<u8>>,
+            extra: BTreeMap<String, u8>,
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Id {
+            id: u8,
+        }
+
+        let user = User {
+            id: 1,
+            is_admin: true,
+            tags: vec!["a".to_string(), "b".to_string()],
+            groups: vec![vec![], vec![2]],
+            extra: BTreeMap::new(),
+        };
+        let mut string = String::new();
+        user.serialize(&mut crate::Serializer::new(&mut string).with_terse(true))?;
+        assert!(!string.contains("the list") && !string.contains("the `id`"));
+        assert_eq!(user, from_str(&string)?);
+        assert_eq!(Id { id: 1 }, from_str(&string)?);
+        assert_eq!(
+            json!({"id": 1, "is admin": true, "tags": ["a", "b"], "groups": [[], [2]], "extra": {}}),
+            from_str::<Value>(&string)?
+        );
+
+        assert_eq!(
+            vec![1, 2],
+            from_str::<Vec<u8>>("list where an item is 1 and another item is 2")?
+        );
+        assert_eq!(json!({"a": 1}), from_str::<Value>("object where `a` is 1")?);
+        assert_eq!(json!([]), from_str::<Value>("empty list")?);
+        assert_eq!(json!(null), from_str::<Value>("empty")?);
+        // a name can't go without its `the` as it would read as a string
+        match from_str::<Id>("`id` where `id` is 1") {
+            Err(Error::ExpectedKeyWord(keyword)) => assert_eq!(keyword, "the"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_scopes() {
+        let unknown = |res: Result<Value>| match res {
+            Err(Error::UnknownScope(scope)) => scope,
+            res => panic!("unexpected result {:?}", res),
+        };
+        assert_eq!(
+            unknown(from_str(
+                "the list where an item is 1 and another item of `the wrong list` is 2"
+            )),
+            "the wrong list"
+        );
+        assert_eq!(
+            unknown(from_str(
+                "the object henceforth `the object` where the `a` is 1 and the `b` of `other` is 2"
+            )),
+            "other"
+        );
+        assert_eq!(
+            unknown(from_str("the list where an item of `the list` is 1")),
+            "the list"
+        );
+        assert_eq!(
+            from_str::<Value>("the list where an item is 1 and another item of `x` is 2")
+                .unwrap_err()
+                .to_string(),
+            "unknown scope 'x'"
+        );
+        match from_str::<Vec<u8>>("the list where an item is 1 and the `a` is 2") {
+            Err(Error::UnexpectedToken(token)) => assert_eq!(token, "the"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        match from_str::<Value>("the object where the `a` is 1 and another item is 2") {
+            Err(Error::UnexpectedToken(token)) => assert_eq!(token, "another"),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        // nested compounds still end at an entry of a higher scope
+        assert_eq!(
+            json!({"a": [1, 2], "b": 3}),
+            from_str::<Value>(
+                "the object henceforth `the object` where the `a` is the list henceforth \
+                 `the object a list` where an item is 1 and another item is 2 and the `b` of \
+                 `the object` is 3"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn deserialize_custom_keywords() -> Result<()> {
+        let keywords = Keywords {
+            the: "de",
+            list: "lijst",
+            object: "object",
+            r#where: "waar",
+            a: "een",
+            an: "een",
+            item: "element",
+            is: "is",
+            and: "en",
+            another: "nog",
+            ..Keywords::english()
+        };
+        let mut deserializer =
+            Deserializer::from_str("De lijst waar een element is 1 en nog element is 2")
+                .with_keywords(keywords);
+        assert_eq!(vec![1, 2], Vec::<u8>::deserialize(&mut deserializer)?);
+
+        let mut deserializer =
+            Deserializer::from_str("de object waar de `key` is `value`").with_keywords(keywords);
+        assert_eq!(
+            json!({"key": "value"}),
+            Value::deserialize(&mut deserializer)?
+        );
+
+        let mut deserializer =
+            Deserializer::from_str("de lijst waar een element is 1 en nog element is niets")
+                .with_keywords(Keywords {
+                    nothing: "niets",
+                    ..keywords
+                });
+        assert_eq!(
+            vec![Some(1), None],
+            Vec::<Option<u8>>::deserialize(&mut deserializer)?
+        );
+
+        let mut deserializer =
+            Deserializer::from_str("de lijst waar een element 1").with_keywords(keywords);
+        match Vec::<u8>::deserialize(&mut deserializer) {
+            Err(Error::ExpectedKeyWord(keyword)) => assert_eq!(keyword, "is"),
+            res => panic!("unexpected result {:?}", res),
+        }
+
+        let mut deserializer = Deserializer::from_str("de lijst waar een element is 1")
+            .with_keywords(Keywords {
+                is: "ist",
+                ..keywords
+            });
+        match Vec::<u8>::deserialize(&mut deserializer) {
+            Err(Error::ExpectedKeyWord(keyword)) => assert_eq!(keyword, "ist"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_item_noun() -> Result<()> {
+        let mut deserializer =
+            Deserializer::from_str("the list where a thing is 1 and another thing is 2")
+                .with_item_noun("thing");
+        assert_eq!(vec![1, 2], Vec::<u8>::deserialize(&mut deserializer)?);
+
+        let mut deserializer =
+            Deserializer::from_str("The list where An Entry is 1").with_item_noun("entry");
+        assert_eq!(json!([1]), Value::deserialize(&mut deserializer)?);
+
+        let mut deserializer =
+            Deserializer::from_str("the list where an item is 1").with_item_noun("thing");
+        assert!(Vec::<u8>::deserialize(&mut deserializer).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn item_noun_round_trip() -> Result<()> {
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct Transaction {
+            from: String,
+            amount: u8,
+        }
+
+        let transactions = vec![
+            Transaction {
+                from: "alice".to_string(),
+                amount: 10,
+            },
+            Transaction {
+                from: "bob".to_string(),
+                amount: 5,
+            },
+        ];
+        for ordinal_items in &[false, true] {
+            let mut string = String::new();
+            transactions.serialize(
+                &mut crate::Serializer::new(&mut string)
+                    .with_item_noun("transaction")
+                    .with_ordinal_items(*ordinal_items),
+            )?;
+            assert!(string.contains("transaction is the `transaction`"));
+            let mut deserializer = Deserializer::from_str(&string).with_item_noun("transaction");
+            assert_eq!(
+                transactions,
+                Vec::<Transaction>::deserialize(&mut deserializer)?
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn deserialize_map() -> Result<()> {
         assert_eq!(
@@ -1245,6 +2429,22 @@ mod tests {
                 .collect::<BTreeMap<bool, u8>>(),
             from_str::<BTreeMap<bool, u8>>("the object where true is 1 and false is 0")?
         );
+        assert_eq!(
+            vec![(u128::MAX, 1)]
+                .into_iter()
+                .collect::<BTreeMap<u128, u8>>(),
+            from_str::<BTreeMap<u128, u8>>(
+                "the object where 340282366920938463463374607431768211455 is 1"
+            )?
+        );
+        assert_eq!(
+            vec![(i128::MIN, u128::MAX)]
+                .into_iter()
+                .collect::<BTreeMap<i128, u128>>(),
+            from_str::<BTreeMap<i128, u128>>(
+                "the object where -170141183460469231731687303715884105728 is 340282366920938463463374607431768211455"
+            )?
+        );
         assert_eq!(
             json!({"red": 100, "green": 200, "blue": 50}),
             from_str::<Value>("the object where `red` is 100 and `green` is 200 and `blue` is 50")?
@@ -1253,6 +2453,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn deserialize_case_insensitive() -> Result<()> {
+        assert_eq!(
+            vec![1, 2],
+            from_str::<Vec<i64>>("The list Where An item Is 1 AND another item is 2")?
+        );
+        assert_eq!(
+            json!({"Name": "The `Rob`"}),
+            from_str::<Value>(r"The object where The `Name` Is `The \`Rob\``")?
+        );
+        assert!(from_str::<bool>("True")?);
+        assert_eq!((), from_str::<()>("Nothing")?);
+
+        let mut deserializer = Deserializer::from_str("The list where an item is 1");
+        assert_eq!(vec![1], Vec::<i64>::deserialize(&mut deserializer)?);
+
+        let mut deserializer =
+            Deserializer::from_str("The list where an item is 1").with_case_sensitive(true);
+        assert!(Vec::<i64>::deserialize(&mut deserializer).is_err());
+        let mut deserializer =
+            Deserializer::from_str("the list Where an item is 1").with_case_sensitive(true);
+        assert!(Vec::<i64>::deserialize(&mut deserializer).is_err());
+        let mut deserializer =
+            Deserializer::from_str("the list where an item is 1").with_case_sensitive(true);
+        assert_eq!(vec![1], Vec::<i64>::deserialize(&mut deserializer)?);
+        Ok(())
+    }
+
     #[test]
     fn deserialize_struct() -> Result<()> {
         #[derive(Eq, PartialEq, Debug, Deserialize)]
@@ -1277,6 +2505,115 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn deserialize_ignored() -> Result<()> {
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        enum Kind {
+            Plain,
+            Wrapped(u8),
+        }
+
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct Extra {
+            lists: Vec<Vec<u16>>,
+            kinds: Vec<Kind>,
+            nothing: Option<u8>,
+        }
+
+        #[derive(Serialize)]
+        struct Full {
+            extra: Extra,
+            id: u8,
+            more: Extra,
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Partial {
+            id: u8,
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Skipped {
+            #[serde(skip_deserializing)]
+            id: u8,
+            extra: de::IgnoredAny,
+        }
+
+        let extra = || Extra {
+            lists: vec![(0..1000).collect(), vec![], (0..1000).collect()],
+            kinds: vec![Kind::Plain, Kind::Wrapped(1)],
+            nothing: None,
+        };
+        let string = to_string(&Full {
+            extra: extra(),
+            id: 7,
+            more: extra(),
+        })?;
+        assert_eq!(Partial { id: 7 }, from_str(&string)?);
+        assert_eq!(
+            Skipped {
+                id: 0,
+                extra: de::IgnoredAny
+            },
+            from_str("the `skipped` where the `extra` is the `extra` where the `id` is 1")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn deserialize_reader() -> Result<()> {
+        use std::io::Cursor;
+
+        #[derive(Eq, PartialEq, Debug, Deserialize)]
+        struct User {
+            id: usize,
+            user_name: String,
+        }
+        let reader =
+            Cursor::new(b"the `user` where the `user name` is `rob` and the `id` is 1".to_vec());
+        assert_eq!(
+            User {
+                id: 1,
+                user_name: "rob".to_string()
+            },
+            from_reader::<_, User>(reader)?
+        );
+        assert!(matches!(
+            from_reader::<_, String>(Cursor::new(vec![b'`', 0xff, b'`'])),
+            Err(Error::InvalidUtf8)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_depth_limit() -> Result<()> {
+        let mut src = String::new();
+        for _ in 0..10_000 {
+            src.push_str("the list where an item is ");
+        }
+        src.push('1');
+        assert!(matches!(
+            from_str::<Value>(&src),
+            Err(Error::DepthLimitExceeded)
+        ));
+
+        let src = "the list where an item is the list where an item is 1 and another item is 2";
+        let mut deserializer = Deserializer::from_str(src).with_max_depth(1);
+        assert!(matches!(
+            Vec::<Vec<u8>>::deserialize(&mut deserializer),
+            Err(Error::DepthLimitExceeded)
+        ));
+        // the depth is released once a nested value is finished
+        let src = "the list henceforth `the list` where an item is the list where an item is 1 and another item of `the list` is the list where an item is 2";
+        let mut deserializer = Deserializer::from_str(src).with_max_depth(2);
+        assert_eq!(
+            vec![vec![1], vec![2]],
+            Vec::<Vec<u8>>::deserialize(&mut deserializer)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn deserialize_nested_list() -> Result<()> {
         assert_eq!((1, (2, 3), 4), from_str::<(u8, (u8, u8), u8)>("the list henceforth `the list` where an item is 1 and another item is the list where an item is 2 and another item is 3 and another item of `the list` is 4")?);
@@ -1407,6 +2744,60 @@ mod tests {
             )?
         );
 
+        // unknown variants are reported unescaped
+        let err = from_str::<ExampleEnum>(r"the `odd\`variant` which is 1").unwrap_err();
+        assert!(err.to_string().contains("`Odd`variant`"), "{}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_tagged_enum() -> Result<()> {
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        #[serde(tag = "kind", content = "value")]
+        enum Adjacent {
+            Empty,
+            Count(u8),
+            Pair(u8, String),
+            Named { id: u8 },
+        }
+
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        #[serde(tag = "kind")]
+        enum Internal {
+            Empty,
+            Named { id: u8 },
+        }
+
+        assert_eq!(
+            to_string(&Adjacent::Count(3))?,
+            "the `adjacent` where the `kind` is `count` and the `value` is 3"
+        );
+        assert_eq!(
+            to_string(&Adjacent::Named { id: 1 })?,
+            "the `adjacent` where the `kind` is `named` and the `value` is the `named` where \
+             the `id` is 1"
+        );
+        let values = vec![
+            Adjacent::Empty,
+            Adjacent::Count(3),
+            Adjacent::Pair(1, "one".to_string()),
+            Adjacent::Named { id: 1 },
+        ];
+        for value in &values {
+            assert_eq!(*value, from_str::<Adjacent>(&to_string(value)?)?);
+        }
+        assert_eq!(values, from_str::<Vec<Adjacent>>(&to_string(&values)?)?);
+
+        for value in &[Internal::Empty, Internal::Named { id: 1 }] {
+            assert_eq!(*value, from_str::<Internal>(&to_string(value)?)?);
+        }
+
+        // named objects are only read as enums when they are a newtype variant
+        assert_eq!(
+            json!({"id": 1}),
+            from_str::<Value>("the `named` where the `id` is 1")?
+        );
         Ok(())
     }
 }
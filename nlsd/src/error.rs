@@ -7,22 +7,38 @@ use serde::{de, ser};
 pub enum Error {
     Custom(String),
     Fmt(fmt::Error),
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
     InvalidUtf8,
+    EmptyInput,
     Parse(nl_parser::ParseError),
+    Syntax {
+        error: nl_parser::ParseError,
+        line: usize,
+        column: usize,
+    },
     ExpectedBool,
     ExpectedNull,
     ExpectedInteger,
     ExpectedFloat,
     ExpectedUnsigned,
+    NumberOutOfRange,
+    NonFiniteFloat,
+    DepthLimitExceeded,
     ExpectedChar,
     ExpectedString,
+    ExpectedBytes,
     ExpectedKeyWord(&'static str),
+    UnexpectedToken(String),
     ExpectedObjectDescriptor,
     ExpectedObjectEntry,
     ExpectedListItem,
     ExpectedPrimitiveMapKey,
     ExpectedStringMapKey,
     ShouldBeDeclaredEmpty,
+    DeclaredEmptyWithEntries,
+    UnknownScope(String),
+    DuplicateKey(String),
     ExpectedUnitVariant,
     UnexpectedUnitVariant,
 }
@@ -36,6 +52,13 @@ impl From<fmt::Error> for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
 impl ser::Error for Error {
     fn custom<T>(msg: T) -> Self
     where
@@ -71,18 +94,38 @@ impl fmt::Display for Error {
         match self {
             Self::Custom(msg) => f.write_fmt(format_args!("custom: {}", msg)),
             Self::Fmt(err) => f.write_fmt(format_args!("io: {}", err)),
+            #[cfg(feature = "std")]
+            Self::Io(err) => f.write_fmt(format_args!("io: {}", err)),
             Self::InvalidUtf8 => f.write_str("strings must be valid utf8"),
+            Self::EmptyInput => f.write_str("empty document"),
             Self::Parse(err) => f.write_fmt(format_args!("parse error: {}", err)),
+            Self::Syntax {
+                error,
+                line,
+                column,
+            } => f.write_fmt(format_args!(
+                "parse error: {} at line {}, column {}",
+                error.message(),
+                line,
+                column
+            )),
             Self::ExpectedBool => f.write_str("expected boolean"),
             Self::ExpectedNull => f.write_str("expected null"),
             Self::ExpectedInteger => f.write_str("expected integer"),
             Self::ExpectedFloat => f.write_str("expected float"),
             Self::ExpectedUnsigned => f.write_str("expected unsigned"),
+            Self::NumberOutOfRange => f.write_str("number out of range"),
+            Self::DepthLimitExceeded => f.write_str("maximum nesting depth exceeded"),
+            Self::NonFiniteFloat => f.write_str("NaN and infinite floats can not be serialized"),
             Self::ExpectedChar => f.write_str("expected char"),
             Self::ExpectedString => f.write_str("expected string"),
+            Self::ExpectedBytes => f.write_str("expected hex encoded bytes"),
             Self::ExpectedKeyWord(keyword) => {
                 f.write_fmt(format_args!("expected keyword '{}'", keyword))
             }
+            Self::UnexpectedToken(token) => {
+                f.write_fmt(format_args!("unexpected token '{}'", token))
+            }
             Self::ExpectedObjectDescriptor => f.write_str("expected object descriptor"),
             Self::ExpectedObjectEntry => f.write_str("expected object entry"),
             Self::ExpectedListItem => f.write_str("expected list item"),
@@ -91,6 +134,11 @@ impl fmt::Display for Error {
             Self::ShouldBeDeclaredEmpty => {
                 f.write_str("empty objects should be declared as 'empty'")
             }
+            Self::DeclaredEmptyWithEntries => {
+                f.write_str("objects declared as 'empty' can not have entries")
+            }
+            Self::UnknownScope(scope) => f.write_fmt(format_args!("unknown scope '{}'", scope)),
+            Self::DuplicateKey(key) => f.write_fmt(format_args!("duplicate key '{}'", key)),
             Self::ExpectedUnitVariant => f.write_str("expected unit variant"),
             Self::UnexpectedUnitVariant => f.write_str("unexpected unit variant"),
         }
@@ -1,6 +1,6 @@
 use crate::de::Deserializer;
 use crate::error::Result;
-use crate::ser::Serializer;
+use crate::ser::{Serializer, SerializerBuilder};
 use alloc::fmt::Write;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -15,6 +15,18 @@ where
     T::deserialize(&mut deserializer)
 }
 
+/// deserialize an instance of `T` from the start of NLSD text, returning the value and the
+/// remaining text which was not deserialized. A period ending the value like a sentence is skipped
+pub fn from_str_partial<'de, T>(s: &'de str) -> Result<(T, &'de str)>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::from_str(s);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.parse_terminal_period();
+    Ok((value, deserializer.rest()))
+}
+
 /// deserialize an instance of `T` from NLSD bytes
 pub fn from_slice<'de, T>(s: &'de [u8]) -> Result<T>
 where
@@ -23,31 +35,65 @@ where
     from_str(core::str::from_utf8(s)?)
 }
 
+/// deserialize an instance of `T` from a reader of NLSD text. The whole input is read into a buffer
+/// before deserializing
+#[cfg(feature = "std")]
+pub fn from_reader<R, T>(mut reader: R) -> Result<T>
+where
+    R: std::io::Read,
+    T: serde::de::DeserializeOwned,
+{
+    let mut buffer = Vec::new();
+    reader.read_to_end(&mut buffer)?;
+    from_slice(&buffer)
+}
+
 /// serialize an instance of `T` to a string
-pub fn to_string<T: ?Sized>(value: &T) -> Result<String>
+pub fn to_string<T>(value: &T) -> Result<String>
 where
-    T: Serialize,
+    T: ?Sized + Serialize,
 {
     let mut writer = String::new();
     to_writer(&mut writer, value)?;
     Ok(writer)
 }
 
+/// serialize an instance of `T` to a string with every `and` clause on its own indented line
+pub fn to_string_pretty<T>(value: &T) -> Result<String>
+where
+    T: ?Sized + Serialize,
+{
+    let mut writer = String::new();
+    to_writer_with(&mut writer, value, &SerializerBuilder::new().pretty(true))?;
+    Ok(writer)
+}
+
 /// serialize an instance of `T` to bytes
-pub fn to_vec<T: ?Sized>(value: &T) -> Result<Vec<u8>>
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
 where
-    T: Serialize,
+    T: ?Sized + Serialize,
 {
     Ok(to_string(value)?.into_bytes())
 }
 
 /// serialize an instance of `T` to a writer
-pub fn to_writer<W, T: ?Sized>(writer: W, value: &T) -> Result<()>
+pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
 where
     W: Write,
-    T: Serialize,
+    T: ?Sized + Serialize,
 {
     let mut ser = Serializer::new(writer);
     value.serialize(&mut ser)?;
     Ok(())
 }
+
+/// serialize an instance of `T` to a writer with a serializer built from `builder`
+pub fn to_writer_with<W, T>(writer: W, value: &T, builder: &SerializerBuilder) -> Result<()>
+where
+    W: Write,
+    T: ?Sized + Serialize,
+{
+    let mut ser = builder.build(writer);
+    value.serialize(&mut ser)?;
+    Ok(())
+}
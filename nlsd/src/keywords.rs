@@ -0,0 +1,99 @@
+use crate::naming::indefinite_article;
+use crate::DEFAULT_ITEM_NOUN;
+
+/// The words which give an NLSD its structure. Defaults to English, but another vocabulary can
+/// be used with `Serializer::with_keywords` and `Deserializer::with_keywords`
+///
+/// ```
+/// # use nlsd::{Deserializer, Keywords, SerializerBuilder};
+/// # use serde::Deserialize;
+/// let keywords = Keywords {
+///     the: "die",
+///     list: "Liste",
+///     r#where: "wobei",
+///     a: "ein",
+///     an: "ein",
+///     item: "Element",
+///     is: "ist",
+///     and: "und",
+///     another: "weiteres",
+///     ..Keywords::english()
+/// };
+/// let mut out = String::new();
+/// nlsd::to_writer_with(&mut out, &vec![1, 2], &SerializerBuilder::new().keywords(keywords)).unwrap();
+/// assert_eq!(out, "die Liste wobei ein Element ist 1 und weiteres Element ist 2");
+///
+/// let mut deserializer = Deserializer::from_str(&out).with_keywords(keywords);
+/// assert_eq!(vec![1, 2], Vec::<u8>::deserialize(&mut deserializer).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keywords {
+    /// Introduces every list and object, e.g. `the list`
+    pub the: &'static str,
+    /// Describes an unnamed object
+    pub object: &'static str,
+    /// Describes an unnamed list
+    pub list: &'static str,
+    /// Describes a list or object without any entries
+    pub empty: &'static str,
+    /// Describes a missing optional value
+    pub nothing: &'static str,
+    /// Introduces the name of a scope
+    pub henceforth: &'static str,
+    /// Introduces the first entry of a list or object
+    pub r#where: &'static str,
+    /// The article before an item noun starting with a consonant
+    pub a: &'static str,
+    /// The article before an item noun starting with a vowel
+    pub an: &'static str,
+    /// The noun used for the items of a list
+    pub item: &'static str,
+    /// Refers an entry to a named scope
+    pub of: &'static str,
+    /// Introduces the value of an enum variant
+    pub which: &'static str,
+    /// Introduces the value of an entry
+    pub is: &'static str,
+    /// Joins the entries of a list or object
+    pub and: &'static str,
+    /// Introduces every item of a list after the first
+    pub another: &'static str,
+}
+
+impl Keywords {
+    /// The English keywords
+    pub const fn english() -> Self {
+        Self {
+            the: "the",
+            object: "object",
+            list: "list",
+            empty: "empty",
+            nothing: "nothing",
+            henceforth: "henceforth",
+            r#where: "where",
+            a: "a",
+            an: "an",
+            item: DEFAULT_ITEM_NOUN,
+            of: "of",
+            which: "which",
+            is: "is",
+            and: "and",
+            another: "another",
+        }
+    }
+
+    /// The article agreeing with the item noun
+    pub(crate) fn item_article(&self) -> &'static str {
+        if indefinite_article(self.item) == "an" {
+            self.an
+        } else {
+            self.a
+        }
+    }
+}
+
+impl Default for Keywords {
+    fn default() -> Self {
+        Self::english()
+    }
+}
@@ -3,6 +3,18 @@
 //! objects respectively and converts the from and to English. See the
 //! [README](https://github.com/RiddleAndCode/nlsd/blob/master/nlsd/README.md) for more information
 //! on the specifications.
+//!
+//! The tokenizer the deserializer is built on is re-exported so that other natural language
+//! formats can be read the same way
+//!
+//! ```
+//! # use nlsd::{parse_next, parse_token, Number, Parsed};
+//! let (_, token, rest) = parse_token("the `list` where").unwrap();
+//! assert_eq!(token, "the");
+//! let (_, parsed, _) = parse_next(rest).unwrap();
+//! assert_eq!(parsed, Parsed::Str("list"));
+//! assert_eq!(parse_next("12").unwrap().1, Parsed::Number(Number::Integer(12)));
+//! ```
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "std")]
@@ -14,9 +26,23 @@ extern crate alloc;
 mod de;
 mod error;
 mod helpers;
+mod keywords;
+pub mod naming;
 mod ser;
 
 pub use de::Deserializer;
 pub use error::{Error, Result};
 pub use helpers::*;
-pub use ser::Serializer;
+pub use keywords::Keywords;
+pub use nl_parser::{
+    line_column, parse_f32, parse_next, parse_next_spanned, parse_next_spanned_with,
+    parse_next_with, parse_number, parse_string, parse_string_with, parse_token, unescape_str,
+    Number, ParseError, ParseResult, Parsed, DEFAULT_DELIMITER,
+};
+pub use ser::{escape_str, format_str, BoolStyle, Serializer, SerializerBuilder};
+
+/// The default limit on how deeply lists and objects may be nested
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// The default noun for the items of a list
+pub const DEFAULT_ITEM_NOUN: &str = "item";
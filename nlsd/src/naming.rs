@@ -0,0 +1,440 @@
+//! Conversions between Rust identifiers and the words used to write them in NLSD
+use alloc::string::{String, ToString};
+
+pub use nl_parser::{ordinal, parse_ordinal};
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [(&str, u64); 3] = [
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+];
+
+/// Turn an identifier in snake case, camel case or upper camel case into space separated words.
+/// Words are lowercased except for acronyms which keep their case
+///
+/// ```
+/// # use nlsd::naming::humanize;
+/// assert_eq!(humanize("userID"), "user ID");
+/// assert_eq!(humanize("first_name"), "first name");
+/// assert_eq!(humanize("HTTPRequest"), "HTTP request");
+/// ```
+pub fn humanize(string: &str) -> String {
+    let mut out = String::new();
+    humanize_each(string, |ch| out.push(ch));
+    out.trim().to_string()
+}
+
+/// Whether `humanize(ident)` equals `string`, compared character by character so no string is
+/// allocated
+pub(crate) fn humanize_eq(ident: &str, string: &str) -> bool {
+    let mut rest = string.chars();
+    // where the target was before the current run of whitespace, which is trimmed if it ends
+    // the humanized string
+    let mut before_whitespace = None;
+    let mut whitespace_matches = true;
+    let mut is_start = true;
+    let mut matches = true;
+    humanize_each(ident, |ch| {
+        if !matches || is_start && ch.is_whitespace() {
+            return;
+        }
+        is_start = false;
+        if ch.is_whitespace() {
+            if before_whitespace.is_none() {
+                before_whitespace = Some(rest.clone());
+                whitespace_matches = true;
+            }
+            whitespace_matches = whitespace_matches && rest.next() == Some(ch);
+        } else {
+            matches = before_whitespace.take().is_none() || whitespace_matches;
+            matches = matches && rest.next() == Some(ch);
+        }
+    });
+    matches && before_whitespace.unwrap_or(rest).next().is_none()
+}
+
+/// Walk the characters of `humanize` before they are trimmed
+fn humanize_each(string: &str, emit: impl FnMut(char)) {
+    let mut out = Emitter { emit, last: None };
+    // the uppercase characters not yet written lie between these byte indices, only separated by
+    // underscores or whitespace
+    let mut buffer: Option<(usize, usize)> = None;
+    let mut buffer_len = 0;
+    for (i, ch) in string.char_indices() {
+        if ch == '_' {
+            out.push_space();
+        } else if ch.is_whitespace() {
+            out.push(ch);
+        } else if ch.is_uppercase() {
+            let start = buffer.map_or(i, |(start, _)| start);
+            buffer = Some((start, i + ch.len_utf8()));
+            buffer_len += ch.len_utf8();
+        } else if let Some((start, end)) = buffer.take() {
+            let mut uppercase = string[start..end].chars().filter(|ch| ch.is_uppercase());
+            if buffer_len > 2 {
+                let last = uppercase.next_back().unwrap();
+                out.push_space();
+                uppercase.for_each(|ch| out.push(ch));
+                out.push(' ');
+                last.to_lowercase().for_each(|ch| out.push(ch));
+            } else {
+                for bch in uppercase {
+                    out.push_space();
+                    bch.to_lowercase().for_each(|ch| out.push(ch));
+                }
+            }
+            buffer_len = 0;
+            out.push(ch);
+        } else {
+            out.push(ch)
+        }
+    }
+    if let Some((start, end)) = buffer {
+        out.push_space();
+        string[start..end]
+            .chars()
+            .filter(|ch| ch.is_uppercase())
+            .for_each(|ch| out.push(ch));
+    }
+}
+
+/// Passes on the characters of a humanized string, remembering the last to separate words
+struct Emitter<F> {
+    emit: F,
+    last: Option<char>,
+}
+
+impl<F: FnMut(char)> Emitter<F> {
+    fn push(&mut self, ch: char) {
+        (self.emit)(ch);
+        self.last = Some(ch);
+    }
+
+    /// Separate words without doubling up on whitespace, e.g. in `Is_Done`
+    fn push_space(&mut self) {
+        if !self.last.is_some_and(char::is_whitespace) {
+            self.push(' ');
+        }
+    }
+}
+
+/// Join space separated words into a snake case identifier
+///
+/// ```
+/// # use nlsd::naming::{dehumanize_snake, humanize};
+/// assert_eq!(dehumanize_snake("first name"), "first_name");
+/// assert_eq!(dehumanize_snake(&humanize("first_name")), "first_name");
+/// assert_eq!(dehumanize_snake("user ID"), "user_ID");
+/// ```
+pub fn dehumanize_snake(string: &str) -> String {
+    dehumanize_snake_chars(string).collect()
+}
+
+/// The characters of `dehumanize_snake` without collecting them into a `String`
+pub(crate) fn dehumanize_snake_chars(string: &str) -> impl Iterator<Item = char> + '_ {
+    let mut was_whitespace = false;
+    let mut is_first = true;
+    string
+        .chars()
+        .flat_map(move |ch| {
+            if ch.is_whitespace() {
+                was_whitespace = true;
+                return [None, None];
+            }
+            let separator = if was_whitespace && !is_first {
+                Some('_')
+            } else {
+                None
+            };
+            was_whitespace = false;
+            is_first = false;
+            [separator, Some(ch)]
+        })
+        .flatten()
+}
+
+/// Join space separated words into an upper camel case identifier, as used by enum variants
+///
+/// ```
+/// # use nlsd::naming::{dehumanize_camel, humanize};
+/// assert_eq!(dehumanize_camel("user ID"), "UserID");
+/// assert_eq!(dehumanize_camel(&humanize("NewUser")), "NewUser");
+/// ```
+pub fn dehumanize_camel(string: &str) -> String {
+    dehumanize_camel_chars(string).collect()
+}
+
+/// The characters of `dehumanize_camel` without collecting them into a `String`
+pub(crate) fn dehumanize_camel_chars(string: &str) -> impl Iterator<Item = char> + '_ {
+    let mut was_whitespace = false;
+    let mut is_first = true;
+    string.chars().flat_map(move |ch| {
+        let (upper, lower) = if ch.is_whitespace() {
+            was_whitespace = true;
+            (None, None)
+        } else if was_whitespace || is_first {
+            (Some(ch.to_uppercase()), None)
+        } else {
+            (None, Some(ch))
+        };
+        if !ch.is_whitespace() {
+            was_whitespace = false;
+            is_first = false;
+        }
+        upper.into_iter().flatten().chain(lower)
+    })
+}
+
+/// Choose the indefinite article, `a` or `an`, to write before a word. The choice is made on how
+/// the word is likely pronounced so a leading vowel letter is not always enough
+///
+/// ```
+/// # use nlsd::naming::indefinite_article;
+/// assert_eq!(indefinite_article("item"), "an");
+/// assert_eq!(indefinite_article("thing"), "a");
+/// assert_eq!(indefinite_article("user"), "a");
+/// assert_eq!(indefinite_article("hour"), "an");
+/// ```
+pub fn indefinite_article(word: &str) -> &'static str {
+    const CONSONANT_SOUNDS: [&str; 6] = ["uni", "use", "usu", "uti", "eu", "one"];
+    const SILENT_H: [&str; 4] = ["hour", "honest", "honor", "heir"];
+    let word = word.to_lowercase();
+    if CONSONANT_SOUNDS
+        .iter()
+        .any(|prefix| word.starts_with(prefix))
+    {
+        "a"
+    } else if SILENT_H.iter().any(|prefix| word.starts_with(prefix))
+        || word.starts_with(['a', 'e', 'i', 'o', 'u'])
+    {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+/// The part a word plays in a cardinal number
+#[derive(Clone, Copy)]
+enum Cardinal {
+    Zero,
+    Unit(u64),
+    Teen(u64),
+    Ten(u64),
+    Hundred,
+    Scale(u64),
+}
+
+fn cardinal(word: &str) -> Option<Cardinal> {
+    if let Some(i) = UNITS
+        .iter()
+        .position(|unit| unit.eq_ignore_ascii_case(word))
+    {
+        return Some(match i {
+            0 => Cardinal::Zero,
+            1..=9 => Cardinal::Unit(i as u64),
+            _ => Cardinal::Teen(i as u64),
+        });
+    }
+    if let Some(i) = TENS.iter().position(|ten| ten.eq_ignore_ascii_case(word)) {
+        return Some(Cardinal::Ten(i as u64 * 10 + 20));
+    }
+    if "hundred".eq_ignore_ascii_case(word) {
+        return Some(Cardinal::Hundred);
+    }
+    SCALES
+        .iter()
+        .find(|(scale, _)| scale.eq_ignore_ascii_case(word))
+        .map(|(_, scale)| Cardinal::Scale(*scale))
+}
+
+fn cardinal_words(words: &str) -> impl Iterator<Item = &str> {
+    words
+        .split(|ch: char| ch.is_whitespace() || ch == '-')
+        .filter(|word| !word.is_empty())
+}
+
+/// Whether a single token such as `five` or `twenty-one` is made up of cardinal number words
+pub(crate) fn is_cardinal(token: &str) -> bool {
+    token.split('-').all(|word| cardinal(word).is_some())
+}
+
+/// Read an English cardinal number written in words, e.g. `one hundred twenty-one`. Words are
+/// matched case-insensitively and numbers up to the billions can be composed
+///
+/// ```
+/// # use nlsd::naming::parse_cardinal;
+/// assert_eq!(parse_cardinal("five"), Some(5));
+/// assert_eq!(parse_cardinal("twenty one"), Some(21));
+/// assert_eq!(parse_cardinal("two thousand and five"), None);
+/// ```
+pub fn parse_cardinal(words: &str) -> Option<u64> {
+    let mut total = 0u64;
+    let mut group = 0u64;
+    let mut last = None;
+    let mut last_scale = u64::MAX;
+    for word in cardinal_words(words) {
+        let word = cardinal(word)?;
+        match (word, last) {
+            (Cardinal::Zero, None) => (),
+            (
+                Cardinal::Unit(n),
+                None | Some(Cardinal::Ten(_)) | Some(Cardinal::Hundred) | Some(Cardinal::Scale(_)),
+            )
+            | (
+                Cardinal::Teen(n) | Cardinal::Ten(n),
+                None | Some(Cardinal::Hundred) | Some(Cardinal::Scale(_)),
+            ) => group += n,
+            (Cardinal::Hundred, Some(Cardinal::Unit(_)) | Some(Cardinal::Teen(_))) => group *= 100,
+            (
+                Cardinal::Scale(scale),
+                Some(Cardinal::Unit(_))
+                | Some(Cardinal::Teen(_))
+                | Some(Cardinal::Ten(_))
+                | Some(Cardinal::Hundred),
+            ) if scale < last_scale => {
+                total = total.checked_add(group.checked_mul(scale)?)?;
+                group = 0;
+                last_scale = scale;
+            }
+            _ => return None,
+        }
+        last = Some(word);
+    }
+    last?;
+    total.checked_add(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_string() {
+        assert_eq!(humanize("UpperCamelCase"), "upper camel case");
+        assert_eq!(humanize("camelCase"), "camel case");
+        assert_eq!(humanize("snake_case"), "snake case");
+        assert_eq!(humanize("CamelCaseWithACRONYM"), "camel case with ACRONYM");
+        assert_eq!(humanize("middleACRONYMHere"), "middle ACRONYM here");
+        assert_eq!(humanize("ACROBeginning"), "ACRO beginning");
+        assert_eq!(humanize("__some_padded_name"), "some padded name");
+    }
+
+    #[test]
+    fn humanize_eq_string() {
+        let idents = [
+            "UpperCamelCase",
+            "CamelCaseWithACRONYM",
+            "middleACRONYMHere",
+            "__some_padded_name",
+            "A_B_c",
+            "AB c",
+            "Is_Done ",
+            " \tx_",
+            "ÉTÉ",
+            "",
+        ];
+        for ident in idents {
+            let humanized = humanize(ident);
+            assert!(humanize_eq(ident, &humanized), "{:?}", ident);
+            assert!(!humanize_eq(ident, &format!("{} ", humanized)));
+            assert!(!humanize_eq(ident, &format!("{}x", humanized)));
+            if let Some((i, _)) = humanized.char_indices().last() {
+                assert!(!humanize_eq(ident, &humanized[..i]));
+            }
+        }
+        assert!(humanize_eq("a  b", "a  b"));
+        assert!(!humanize_eq("a  b", "a b"));
+    }
+
+    #[test]
+    fn dehumanize_string() {
+        assert_eq!(dehumanize_snake("snake case"), "snake_case");
+        assert_eq!(dehumanize_snake("  padded   name "), "padded_name");
+        assert_eq!(dehumanize_camel("upper camel case"), "UpperCamelCase");
+        assert_eq!(dehumanize_camel("with ACRONYM"), "WithACRONYM");
+    }
+
+    #[test]
+    fn indefinite_articles() {
+        assert_eq!(indefinite_article("item"), "an");
+        assert_eq!(indefinite_article("thing"), "a");
+        assert_eq!(indefinite_article("entry"), "an");
+        assert_eq!(indefinite_article("Element"), "an");
+        assert_eq!(indefinite_article("object"), "an");
+        assert_eq!(indefinite_article("unit"), "a");
+        assert_eq!(indefinite_article("user"), "a");
+        assert_eq!(indefinite_article("umbrella"), "an");
+        assert_eq!(indefinite_article("hour"), "an");
+        assert_eq!(indefinite_article("house"), "a");
+        assert_eq!(indefinite_article(""), "a");
+    }
+
+    #[test]
+    fn cardinals() {
+        assert_eq!(parse_cardinal("zero"), Some(0));
+        assert_eq!(parse_cardinal("five"), Some(5));
+        assert_eq!(parse_cardinal("Fifteen"), Some(15));
+        assert_eq!(parse_cardinal("twenty"), Some(20));
+        assert_eq!(parse_cardinal("twenty one"), Some(21));
+        assert_eq!(parse_cardinal("twenty-one"), Some(21));
+        assert_eq!(parse_cardinal("one hundred"), Some(100));
+        assert_eq!(parse_cardinal("one hundred five"), Some(105));
+        assert_eq!(parse_cardinal("nine hundred ninety-nine"), Some(999));
+        assert_eq!(parse_cardinal("fifteen hundred"), Some(1500));
+        assert_eq!(parse_cardinal("two thousand"), Some(2000));
+        assert_eq!(parse_cardinal("one hundred thousand"), Some(100_000));
+        assert_eq!(
+            parse_cardinal("three million forty thousand seven hundred twelve"),
+            Some(3_040_712)
+        );
+        assert_eq!(
+            parse_cardinal("nine hundred ninety nine billion"),
+            Some(999_000_000_000)
+        );
+
+        assert_eq!(parse_cardinal(""), None);
+        assert_eq!(parse_cardinal("-"), None);
+        assert_eq!(parse_cardinal("five five"), None);
+        assert_eq!(parse_cardinal("one twenty"), None);
+        assert_eq!(parse_cardinal("twenty twenty"), None);
+        assert_eq!(parse_cardinal("zero one"), None);
+        assert_eq!(parse_cardinal("hundred"), None);
+        assert_eq!(parse_cardinal("one hundred hundred"), None);
+        assert_eq!(parse_cardinal("thousand"), None);
+        assert_eq!(parse_cardinal("one thousand two thousand"), None);
+        assert_eq!(parse_cardinal("five apples"), None);
+
+        assert!(is_cardinal("twenty-one"));
+        assert!(is_cardinal("Five"));
+        assert!(!is_cardinal("five-"));
+        assert!(!is_cardinal("-"));
+        assert!(!is_cardinal("item"));
+    }
+}
@@ -1,13 +1,171 @@
 use crate::error::{Error, Result};
+use crate::naming::humanize;
+use crate::{Keywords, DEFAULT_MAX_DEPTH};
+use alloc::borrow::Cow;
 use alloc::fmt::Write;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::mem;
+use nl_parser::{ordinal, unescape_str, DEFAULT_DELIMITER};
 use serde::ser;
 
+/// The indentation of each level of nesting in pretty output
+const INDENT: &str = "  ";
+
+/// The words used to write booleans
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoolStyle {
+    /// `true` and `false`
+    #[default]
+    TrueFalse,
+    /// `yes` and `no`
+    YesNo,
+    /// `on` and `off`
+    OnOff,
+    /// `enabled` and `disabled`
+    EnabledDisabled,
+}
+
+impl BoolStyle {
+    fn word(self, v: bool) -> &'static str {
+        match (self, v) {
+            (BoolStyle::TrueFalse, true) => "true",
+            (BoolStyle::TrueFalse, false) => "false",
+            (BoolStyle::YesNo, true) => "yes",
+            (BoolStyle::YesNo, false) => "no",
+            (BoolStyle::OnOff, true) => "on",
+            (BoolStyle::OnOff, false) => "off",
+            (BoolStyle::EnabledDisabled, true) => "enabled",
+            (BoolStyle::EnabledDisabled, false) => "disabled",
+        }
+    }
+}
+
+/// Options which are carried over to the serializers of nested values
+#[derive(Debug, Clone, Copy)]
+struct Options {
+    bool_style: BoolStyle,
+    delimiter: char,
+    max_depth: usize,
+    keywords: Keywords,
+    pretty: bool,
+    ordinal_items: bool,
+    float_precision: Option<usize>,
+    terse: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            bool_style: BoolStyle::default(),
+            delimiter: DEFAULT_DELIMITER,
+            max_depth: DEFAULT_MAX_DEPTH,
+            keywords: Keywords::english(),
+            pretty: false,
+            ordinal_items: false,
+            float_precision: None,
+            terse: false,
+        }
+    }
+}
+
+/// Collects options to construct `Serializer`s with. A builder can be reused for any number of
+/// serializers
+///
+/// ```
+/// # use nlsd::{BoolStyle, SerializerBuilder};
+/// let builder = SerializerBuilder::new()
+///     .bool_style(BoolStyle::YesNo)
+///     .delimiter('\'');
+/// let mut out = String::new();
+/// nlsd::to_writer_with(&mut out, &(true, "hello"), &builder).unwrap();
+/// assert_eq!(out, "the list where an item is yes and another item is 'hello'");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SerializerBuilder {
+    options: Options,
+}
+
+impl SerializerBuilder {
+    /// Construct a builder with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the words used to write booleans. Defaults to `true` and `false`
+    pub fn bool_style(mut self, bool_style: BoolStyle) -> Self {
+        self.options.bool_style = bool_style;
+        self
+    }
+
+    /// Set the character which surrounds strings. Defaults to a backtick
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.options.delimiter = delimiter;
+        self
+    }
+
+    /// Set how deeply lists and objects may be nested before serializing fails with
+    /// `Error::DepthLimitExceeded`. Defaults to 128
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.options.max_depth = max_depth;
+        self
+    }
+
+    /// Set the noun used for the items of a list. Defaults to `item`. The article before it is
+    /// chosen to agree with the noun, e.g. `a thing`
+    pub fn item_noun(mut self, item_noun: &'static str) -> Self {
+        self.options.keywords.item = item_noun;
+        self
+    }
+
+    /// Set the words which give the document its structure. Defaults to English
+    pub fn keywords(mut self, keywords: Keywords) -> Self {
+        self.options.keywords = keywords;
+        self
+    }
+
+    /// Set whether each `and` clause starts on a new line indented by how deeply it is nested.
+    /// Defaults to `false`
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.options.pretty = pretty;
+        self
+    }
+
+    /// Set whether list items are written by their position, e.g. `the second item`, instead of
+    /// `another item`. Defaults to `false`
+    pub fn ordinal_items(mut self, ordinal_items: bool) -> Self {
+        self.options.ordinal_items = ordinal_items;
+        self
+    }
+
+    /// Round floats to at most `precision` decimal places, e.g. `0.1 + 0.2` is written as `0.3`
+    /// with a precision of 2. Defaults to writing floats at full precision
+    pub fn float_precision(mut self, precision: usize) -> Self {
+        self.options.float_precision = Some(precision);
+        self
+    }
+
+    /// Set whether the `the` is left out before keys and before the `list` and `object` of
+    /// unnamed compounds, e.g. `object where \`a\` is 1`. Named structs and variants keep their
+    /// `the` as their name would otherwise read as a string. Defaults to `false`
+    pub fn terse(mut self, terse: bool) -> Self {
+        self.options.terse = terse;
+        self
+    }
+
+    /// Construct a serializer with these options writing into `writer`
+    pub fn build<W>(&self, writer: W) -> Serializer<W> {
+        Serializer::with_context(writer, Vec::new(), self.options)
+    }
+}
+
 /// A structure that serializes Rust values into a writer `W`
 pub struct Serializer<W> {
     context: Vec<String>,
     writer: W,
+    options: Options,
+    depth: usize,
 }
 
 pub struct Compound<'a, W> {
@@ -22,16 +180,80 @@ pub struct Compound<'a, W> {
 impl<W> Serializer<W> {
     /// Construct a new serializer from a writer `W`
     pub fn new(writer: W) -> Self {
-        Self::with_context(writer, Default::default())
+        SerializerBuilder::new().build(writer)
+    }
+
+    /// Change the options of the serializer with the setters of `SerializerBuilder`
+    fn with_options(mut self, f: impl FnOnce(SerializerBuilder) -> SerializerBuilder) -> Self {
+        self.options = f(SerializerBuilder {
+            options: self.options,
+        })
+        .options;
+        self
+    }
+
+    /// Set the words used to write booleans, see `SerializerBuilder::bool_style`
+    pub fn with_bool_style(self, bool_style: BoolStyle) -> Self {
+        self.with_options(|builder| builder.bool_style(bool_style))
+    }
+
+    /// Set the character which surrounds strings, see `SerializerBuilder::delimiter`
+    pub fn with_delimiter(self, delimiter: char) -> Self {
+        self.with_options(|builder| builder.delimiter(delimiter))
+    }
+
+    /// Set how deeply lists and objects may be nested, see `SerializerBuilder::max_depth`
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        self.with_options(|builder| builder.max_depth(max_depth))
+    }
+
+    /// Set the noun used for the items of a list, see `SerializerBuilder::item_noun`
+    pub fn with_item_noun(self, item_noun: &'static str) -> Self {
+        self.with_options(|builder| builder.item_noun(item_noun))
+    }
+
+    /// Set the words which give the document its structure, see `SerializerBuilder::keywords`
+    pub fn with_keywords(self, keywords: Keywords) -> Self {
+        self.with_options(|builder| builder.keywords(keywords))
+    }
+
+    /// Set whether each `and` clause starts on a new line, see `SerializerBuilder::pretty`
+    pub fn with_pretty(self, pretty: bool) -> Self {
+        self.with_options(|builder| builder.pretty(pretty))
+    }
+
+    /// Set whether list items are written by their position, see `SerializerBuilder::ordinal_items`
+    pub fn with_ordinal_items(self, ordinal_items: bool) -> Self {
+        self.with_options(|builder| builder.ordinal_items(ordinal_items))
+    }
+
+    /// Round floats to at most `precision` decimal places, see `SerializerBuilder::float_precision`
+    pub fn with_float_precision(self, precision: usize) -> Self {
+        self.with_options(|builder| builder.float_precision(precision))
+    }
+
+    /// Set whether the `the` is left out where it is not needed, see `SerializerBuilder::terse`
+    pub fn with_terse(self, terse: bool) -> Self {
+        self.with_options(|builder| builder.terse(terse))
+    }
+
+    fn with_context(writer: W, context: Vec<String>, options: Options) -> Self {
+        Self {
+            context,
+            writer,
+            options,
+            depth: 0,
+        }
     }
 
-    fn with_context(writer: W, context: Vec<String>) -> Self {
-        Self { context, writer }
+    fn format_str(&self, string: &str) -> String {
+        format_str(string, self.options.delimiter)
     }
 
     fn push_named_context(&mut self, name: &str) {
         if self.context.is_empty() {
-            self.context.push(format!("the {}", humanize(name)));
+            let the = self.options.keywords.the;
+            self.context.push(format!("{} {}", the, humanize(name)));
         } else {
             self.context.push(humanize(name));
         }
@@ -39,17 +261,21 @@ impl<W> Serializer<W> {
 
     fn push_list_context(&mut self) {
         if self.context.is_empty() {
-            self.context.push("the list".to_string());
+            let keywords = self.options.keywords;
+            self.context
+                .push(format!("{} {}", keywords.the, keywords.list));
         } else {
-            self.context.push("list".to_string());
+            self.context.push(self.options.keywords.list.to_string());
         }
     }
 
     fn push_object_context(&mut self) {
         if self.context.is_empty() {
-            self.context.push("the object".to_string());
+            let keywords = self.options.keywords;
+            self.context
+                .push(format!("{} {}", keywords.the, keywords.object));
         } else {
-            self.context.push("object".to_string());
+            self.context.push(self.options.keywords.object.to_string());
         }
     }
 
@@ -66,49 +292,77 @@ impl<W> Serializer<W> {
     }
 }
 
-fn format_str(string: &str) -> String {
-    format!("`{}`", escape_str(string))
+/// Escape `\\` and the `delimiter` with a `\\` so the string can be written between delimiters.
+/// This is the reverse of `unescape_str` and only allocates when there is something to escape
+///
+/// ```
+/// # use nlsd::escape_str;
+/// assert_eq!(escape_str("plain", '`'), "plain");
+/// assert_eq!(escape_str(r"a`b\c", '`'), r"a\`b\\c");
+/// ```
+pub fn escape_str(string: &str, delimiter: char) -> Cow<'_, str> {
+    if !string.contains(['\\', delimiter]) {
+        return Cow::Borrowed(string);
+    }
+    let mut out = String::with_capacity(string.len() + 2);
+    for ch in string.chars() {
+        if ch == '\\' || ch == delimiter {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    Cow::Owned(out)
 }
 
-#[inline]
-fn escape_str(string: &str) -> String {
-    string.replace('`', r"\`")
+/// Write a string literal as the serializer does, escaped and surrounded by `delimiter`
+///
+/// ```
+/// # use nlsd::format_str;
+/// assert_eq!(format_str("hello, world", '`'), "`hello, world`");
+/// assert_eq!(format_str("it's", '\''), r"'it\'s'");
+/// ```
+pub fn format_str(string: &str, delimiter: char) -> String {
+    format!(
+        "{}{}{}",
+        delimiter,
+        escape_str(string, delimiter),
+        delimiter
+    )
 }
 
-fn humanize(string: &str) -> String {
-    let mut out = String::new();
-    let mut buffer = String::new();
-    for ch in string.chars() {
-        if ch == '_' {
-            out.push(' ');
-        } else if ch.is_whitespace() {
-            out.push(ch);
-        } else if ch.is_uppercase() {
-            buffer.push(ch);
-        } else if buffer.len() > 2 {
-            let last = buffer.pop().unwrap();
-            out.push(' ');
-            out.push_str(&buffer);
-            buffer.clear();
-            out.push(' ');
-            out.push_str(&last.to_lowercase().to_string());
-            out.push(ch);
-        } else if !buffer.is_empty() {
-            for bch in buffer.chars() {
-                out.push(' ');
-                out.push_str(&bch.to_lowercase().to_string());
-            }
-            buffer.clear();
-            out.push(ch);
-        } else {
-            out.push(ch)
-        }
+/// Undo `format_str`, stripping the delimiters and escapes of a string literal. Anything else,
+/// such as a number, is returned as it is
+fn unformat_str(string: &str, delimiter: char) -> String {
+    match string
+        .strip_prefix(delimiter)
+        .and_then(|rest| rest.strip_suffix(delimiter))
+    {
+        Some(inner) => unescape_str(inner).into_owned(),
+        None => string.to_string(),
     }
-    if !buffer.is_empty() {
-        out.push(' ');
-        out.push_str(&buffer);
+}
+
+/// Round to `precision` decimal places. Floats too large to scale are already whole so are
+/// returned as they are
+fn round_float(v: f64, precision: usize) -> f64 {
+    let scale = 10f64.powi(i32::try_from(precision).unwrap_or(i32::MAX));
+    let scaled = v * scale;
+    if scaled.is_finite() {
+        scaled.round() / scale
+    } else {
+        v
+    }
+}
+
+/// Bytes are written as a lowercase hex string
+fn encode_bytes(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push(HEX[(byte >> 4) as usize] as char);
+        out.push(HEX[(byte & 0xf) as usize] as char);
     }
-    out.trim().to_string()
+    out
 }
 
 impl<'a, W> Compound<'a, W> {
@@ -172,17 +426,35 @@ where
         Ok(())
     }
 
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_fmt(format_args!("{}", v))?;
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_fmt(format_args!("{}", v))?;
+        Ok(())
+    }
+
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
         self.serialize_f64(v as f64)
     }
 
     fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        if !v.is_finite() {
+            return Err(Error::NonFiniteFloat);
+        }
+        let v = match self.options.float_precision {
+            Some(precision) => round_float(v, precision),
+            None => v,
+        };
         self.writer.write_fmt(format_args!("{}", v))?;
         Ok(())
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        self.writer.write_str(format_str(v).as_ref())?;
+        let string = self.format_str(v);
+        self.writer.write_str(&string)?;
         Ok(())
     }
 
@@ -195,10 +467,7 @@ where
     }
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
-        match v {
-            true => self.writer.write_str("true")?,
-            false => self.writer.write_str("false")?,
-        };
+        self.writer.write_str(self.options.bool_style.word(v))?;
         Ok(())
     }
 
@@ -207,24 +476,24 @@ where
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
-        self.writer.write_str("nothing")?;
+        self.writer.write_str(self.options.keywords.nothing)?;
         Ok(())
     }
 
-    fn serialize_some<T: ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         value.serialize(self)
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        self.writer.write_str("empty")?;
+        self.writer.write_str(self.options.keywords.empty)?;
         Ok(())
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        self.serialize_str(core::str::from_utf8(v)?)
+        self.serialize_str(&encode_bytes(v))
     }
 
     fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
@@ -270,13 +539,13 @@ where
         Ok(Compound::new(self, Some(variant)))
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
         _: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         value.serialize(self)
     }
@@ -291,7 +560,7 @@ where
         Ok(Compound::new(self, Some(variant)))
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
         _: &'static str,
         _: u32,
@@ -299,11 +568,13 @@ where
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
-        self.writer.write_str("the ")?;
+        let keywords = self.options.keywords;
+        self.writer.write_fmt(format_args!("{} ", keywords.the))?;
         self.serialize_str(&humanize(variant))?;
-        self.writer.write_str(" which is ")?;
+        self.writer
+            .write_fmt(format_args!(" {} {} ", keywords.which, keywords.is))?;
         value.serialize(self)
     }
 }
@@ -333,56 +604,99 @@ where
     }
 
     fn an_item(&mut self) -> Result<()> {
+        let keywords = self.serializer.options.keywords;
         if self.index == 0 {
-            self.buffer.write_str(" where an ")?;
+            self.buffer
+                .write_fmt(format_args!(" {} ", keywords.r#where))?;
         } else {
-            self.buffer.write_str(" and another ")?;
+            self.clause_break()?;
+            self.buffer.write_fmt(format_args!("{} ", keywords.and))?;
+        }
+        if self.serializer.options.ordinal_items {
+            self.buffer.write_fmt(format_args!(
+                "{} {} ",
+                keywords.the,
+                ordinal(self.index + 1)
+            ))?;
+        } else if self.index == 0 {
+            self.buffer
+                .write_fmt(format_args!("{} ", keywords.item_article()))?;
+        } else {
+            self.buffer
+                .write_fmt(format_args!("{} ", keywords.another))?;
         }
-        self.buffer.write_str("item ")?;
+        self.buffer.write_fmt(format_args!("{} ", keywords.item))?;
         self.index += 1;
-        self.serializer.context.push(format!("item {}", self.index));
+        self.serializer
+            .context
+            .push(format!("{} {}", keywords.item, self.index));
         Ok(())
     }
 
-    fn the_map_key<T: ?Sized>(&mut self, name: &T) -> Result<()>
-    where
-        T: ser::Serialize,
-    {
+    fn where_or_and(&mut self) -> Result<()> {
+        let keywords = self.serializer.options.keywords;
         if self.index == 0 {
-            self.buffer.write_str(" where ")?;
+            self.buffer
+                .write_fmt(format_args!(" {} ", keywords.r#where))?;
+        } else {
+            self.clause_break()?;
+            self.buffer.write_fmt(format_args!("{} ", keywords.and))?;
+        }
+        Ok(())
+    }
+
+    /// Separate the clauses of a list or object with a space or, when pretty, a new line
+    fn clause_break(&mut self) -> Result<()> {
+        if self.serializer.options.pretty {
+            self.buffer.write_char('\n')?;
+            for _ in 0..=self.serializer.depth {
+                self.buffer.write_str(INDENT)?;
+            }
         } else {
-            self.buffer.write_str(" and ")?;
+            self.buffer.write_char(' ')?;
         }
+        Ok(())
+    }
+
+    fn the_map_key<T>(&mut self, name: &T) -> Result<()>
+    where
+        T: ?Sized + ser::Serialize,
+    {
+        self.where_or_and()?;
 
-        let mut serializer = Serializer::with_context(String::new(), Vec::new());
+        let mut serializer =
+            Serializer::with_context(String::new(), Vec::new(), self.serializer.options);
         name.serialize(&mut serializer)?;
         // TODO test if nested struct actually gets caught
         if serializer.context.len() > 1 {
             return Err(Error::ExpectedPrimitiveMapKey);
         }
-        self.buffer.write_str(&mut serializer.writer)?;
+        self.buffer.write_str(&serializer.writer)?;
         self.buffer.write_char(' ')?;
 
         self.index += 1;
-        self.serializer.context.push(escape_str(&serializer.writer));
+        // the scope name is escaped once it is written so it holds the raw key text
+        let delimiter = self.serializer.options.delimiter;
+        self.serializer
+            .context
+            .push(unformat_str(&serializer.writer, delimiter));
         Ok(())
     }
 
     fn the_struct_key(&mut self, name: &'static str) -> Result<()> {
-        if self.index == 0 {
-            self.buffer.write_str(" where ")?;
-        } else {
-            self.buffer.write_str(" and ")?;
-        }
+        self.where_or_and()?;
 
         let name = humanize(name);
         // or other verbs?
-        if name.starts_with("is ") || name.starts_with("has ") {
+        if name.starts_with("is ") || name.starts_with("has ") || self.serializer.options.terse {
             self.buffer
-                .write_fmt(format_args!("{} ", format_str(&name)))?;
+                .write_fmt(format_args!("{} ", self.serializer.format_str(&name)))?;
         } else {
-            self.buffer
-                .write_fmt(format_args!("the {} ", format_str(&name)))?;
+            self.buffer.write_fmt(format_args!(
+                "{} {} ",
+                self.serializer.options.keywords.the,
+                self.serializer.format_str(&name)
+            ))?;
         }
 
         self.index += 1;
@@ -393,8 +707,9 @@ where
     fn of_scope(&mut self) -> Result<()> {
         if self.is_new_scope {
             self.buffer.write_fmt(format_args!(
-                "of {} ",
-                format_str(&self.serializer.parent_scope())
+                "{} {} ",
+                self.serializer.options.keywords.of,
+                self.serializer.format_str(&self.serializer.parent_scope())
             ))?;
             self.is_new_scope = false;
             self.is_leaf = false;
@@ -403,57 +718,78 @@ where
     }
 
     fn is(&mut self) -> Result<()> {
-        self.buffer.write_str("is ")?;
+        self.buffer
+            .write_fmt(format_args!("{} ", self.serializer.options.keywords.is))?;
         Ok(())
     }
 
-    fn value<T: ?Sized>(&mut self, value: &T) -> Result<()>
+    fn value<T>(&mut self, value: &T) -> Result<()>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
-        let mut serializer =
-            Serializer::with_context(self.buffer.clone(), self.serializer.context.clone());
+        if self.serializer.depth >= self.serializer.options.max_depth {
+            return Err(Error::DepthLimitExceeded);
+        }
+        // the buffer and context are moved into the nested serializer and back out again so
+        // serializing stays linear in the length of the output
+        let context_len = self.serializer.context.len();
+        let mut serializer = Serializer::with_context(
+            mem::take(&mut self.buffer),
+            mem::take(&mut self.serializer.context),
+            self.serializer.options,
+        );
+        serializer.depth = self.serializer.depth + 1;
         value.serialize(&mut serializer)?;
         self.buffer = serializer.writer;
-        if serializer.context.len() > self.serializer.context.len() {
+        if serializer.context.len() > context_len {
             self.is_new_scope = true;
         }
+        serializer.context.truncate(context_len);
+        self.serializer.context = serializer.context;
         let _ = self.serializer.context.pop();
         Ok(())
     }
 
     fn the_list(&mut self) -> Result<()> {
+        let keywords = self.serializer.options.keywords;
         let name = if let Some(name) = self.name {
-            format_str(&humanize(name))
+            self.serializer.format_str(&humanize(name))
         } else {
-            "list".to_string()
+            keywords.list.to_string()
         };
-        if self.index > 0 {
+        if self.name.is_some() || !self.serializer.options.terse {
             self.serializer
                 .writer
-                .write_str(format!("the {}", name).as_ref())?;
+                .write_fmt(format_args!("{} ", keywords.the))?;
+        }
+        if self.index > 0 {
+            self.serializer.writer.write_str(&name)?;
         } else {
             self.serializer
                 .writer
-                .write_str(format!("the empty {}", name).as_ref())?;
+                .write_fmt(format_args!("{} {}", keywords.empty, name))?;
         }
         Ok(())
     }
 
     fn the_object(&mut self) -> Result<()> {
+        let keywords = self.serializer.options.keywords;
         let name = if let Some(name) = self.name {
-            format_str(&humanize(name))
+            self.serializer.format_str(&humanize(name))
         } else {
-            "object".to_string()
+            keywords.object.to_string()
         };
-        if self.index > 0 {
+        if self.name.is_some() || !self.serializer.options.terse {
             self.serializer
                 .writer
-                .write_str(format!("the {}", name).as_ref())?;
+                .write_fmt(format_args!("{} ", keywords.the))?;
+        }
+        if self.index > 0 {
+            self.serializer.writer.write_str(&name)?;
         } else {
             self.serializer
                 .writer
-                .write_str(format!("the empty {}", name).as_ref())?;
+                .write_fmt(format_args!("{} {}", keywords.empty, name))?;
         }
         Ok(())
     }
@@ -461,8 +797,9 @@ where
     fn contents(&mut self) -> Result<()> {
         if !self.is_leaf {
             self.serializer.writer.write_fmt(format_args!(
-                " henceforth {}",
-                format_str(&self.serializer.current_scope())
+                " {} {}",
+                self.serializer.options.keywords.henceforth,
+                self.serializer.format_str(&self.serializer.current_scope())
             ))?;
         }
         self.serializer.writer.write_str(&self.buffer)?;
@@ -477,9 +814,9 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         self.init_list();
         self.an_item()?;
@@ -503,9 +840,9 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         <Self as ser::SerializeSeq>::serialize_element(self, value)
     }
@@ -521,9 +858,9 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         <Self as ser::SerializeSeq>::serialize_element(self, value)
     }
@@ -539,9 +876,9 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         <Self as ser::SerializeSeq>::serialize_element(self, value)
     }
@@ -557,18 +894,18 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_key<T: ?Sized>(&mut self, key: &T) -> Result<(), Self::Error>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         self.init_object();
         self.the_map_key(key)?;
         self.of_scope()?;
         Ok(())
     }
-    fn serialize_value<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         self.is()?;
         self.value(value)?;
@@ -588,13 +925,9 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         self.init_object();
         self.the_struct_key(key)?;
@@ -617,13 +950,9 @@ where
     type Ok = ();
     type Error = Error;
 
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         <Self as ser::SerializeStruct>::serialize_field(self, key, value)
     }
@@ -637,23 +966,23 @@ pub mod tests {
     use super::*;
     use crate::helpers::to_string;
     use alloc::collections::BTreeMap;
-    use serde::Serialize;
-
-    #[test]
-    fn humanize_string() {
-        assert_eq!(humanize("UpperCamelCase"), "upper camel case");
-        assert_eq!(humanize("camelCase"), "camel case");
-        assert_eq!(humanize("snake_case"), "snake case");
-        assert_eq!(humanize("CamelCaseWithACRONYM"), "camel case with ACRONYM");
-        assert_eq!(humanize("middleACRONYMHere"), "middle ACRONYM here");
-        assert_eq!(humanize("ACROBeginning"), "ACRO beginning");
-        assert_eq!(humanize("__some_padded_name"), "some padded name");
-    }
+    use serde::{Deserialize, Serialize};
 
     #[test]
     fn serialize_bool() -> Result<()> {
         assert_eq!(to_string(&true)?, "true");
         assert_eq!(to_string(&false)?, "false");
+
+        let mut serializer = Serializer::new(String::new()).with_bool_style(BoolStyle::YesNo);
+        true.serialize(&mut serializer)?;
+        assert_eq!(serializer.writer, "yes");
+
+        let mut serializer = Serializer::new(String::new()).with_bool_style(BoolStyle::OnOff);
+        vec![true, false].serialize(&mut serializer)?;
+        assert_eq!(
+            serializer.writer,
+            "the list where an item is on and another item is off"
+        );
         Ok(())
     }
 
@@ -668,6 +997,28 @@ pub mod tests {
         assert_eq!(to_string(&1.0)?, "1");
         assert_eq!(to_string(&0.1)?, "0.1");
         assert_eq!(to_string(&-1.5)?, "-1.5");
+        assert!(matches!(to_string(&f64::NAN), Err(Error::NonFiniteFloat)));
+        assert!(matches!(
+            to_string(&f64::INFINITY),
+            Err(Error::NonFiniteFloat)
+        ));
+        assert!(matches!(
+            to_string(&f64::NEG_INFINITY),
+            Err(Error::NonFiniteFloat)
+        ));
+        assert!(matches!(to_string(&f32::NAN), Err(Error::NonFiniteFloat)));
+        assert!(matches!(
+            to_string(&vec![1., f64::INFINITY]),
+            Err(Error::NonFiniteFloat)
+        ));
+        assert_eq!(
+            to_string(&u128::MAX)?,
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(
+            to_string(&i128::MIN)?,
+            "-170141183460469231731687303715884105728"
+        );
         Ok(())
     }
 
@@ -685,6 +1036,152 @@ pub mod tests {
         assert_eq!(to_string("cool")?, "`cool`");
         assert_eq!(to_string("don't")?, r"`don't`");
         assert_eq!(to_string("escaped`string")?, r"`escaped\`string`");
+        assert_eq!(to_string(r"C:\path")?, r"`C:\\path`");
+        assert_eq!(to_string(r"a\`b")?, r"`a\\\`b`");
+        for string in &[r"C:\path", r"a\`b", r"ends with \", r"\\`\"] {
+            assert_eq!(&crate::from_str::<String>(&to_string(string)?)?, string);
+        }
+
+        let mut serializer = Serializer::new(String::new()).with_delimiter('\'');
+        "don't `quote`".serialize(&mut serializer)?;
+        assert_eq!(serializer.writer, r"'don\'t `quote`'");
+
+        let mut serializer = Serializer::new(String::new()).with_delimiter('"');
+        vec![("key", "value")]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>()
+            .serialize(&mut serializer)?;
+        assert_eq!(serializer.writer, r#"the object where "key" is "value""#);
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_depth_limit() -> Result<()> {
+        let mut value = serde_json::json!(1);
+        for _ in 0..1000 {
+            value = serde_json::Value::Array(vec![value]);
+        }
+        assert!(matches!(to_string(&value), Err(Error::DepthLimitExceeded)));
+
+        let nested = vec![vec![vec![1]]];
+        let mut serializer = Serializer::new(String::new()).with_max_depth(2);
+        assert!(matches!(
+            nested.serialize(&mut serializer),
+            Err(Error::DepthLimitExceeded)
+        ));
+        let mut serializer = Serializer::new(String::new()).with_max_depth(3);
+        nested.serialize(&mut serializer)?;
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_item_noun() -> Result<()> {
+        assert_eq!(
+            to_string(&vec![1, 2])?,
+            "the list where an item is 1 and another item is 2"
+        );
+
+        let mut serializer = Serializer::new(String::new()).with_item_noun("thing");
+        vec![1, 2].serialize(&mut serializer)?;
+        assert_eq!(
+            serializer.writer,
+            "the list where a thing is 1 and another thing is 2"
+        );
+
+        let mut serializer = Serializer::new(String::new()).with_item_noun("entry");
+        vec![1].serialize(&mut serializer)?;
+        assert_eq!(serializer.writer, "the list where an entry is 1");
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_custom_keywords() -> Result<()> {
+        #[derive(Serialize)]
+        enum Enum {
+            Variant(Vec<u8>),
+        }
+
+        let keywords = Keywords {
+            the: "de",
+            list: "lijst",
+            empty: "lege",
+            nothing: "niets",
+            henceforth: "voortaan",
+            r#where: "waar",
+            a: "een",
+            an: "een",
+            item: "element",
+            of: "van",
+            which: "welke",
+            and: "en",
+            another: "nog",
+            ..Keywords::english()
+        };
+        let mut serializer = Serializer::new(String::new()).with_keywords(keywords);
+        Enum::Variant(vec![1, 2]).serialize(&mut serializer)?;
+        assert_eq!(
+            serializer.writer,
+            "de `variant` welke is de lijst waar een element is 1 en nog element is 2"
+        );
+
+        let mut serializer = Serializer::new(String::new()).with_keywords(keywords);
+        vec![vec![1], vec![]].serialize(&mut serializer)?;
+        assert_eq!(serializer.writer, "de lijst voortaan `de lijst` waar een element is de lijst waar een element is 1 en nog element van `de lijst` is de lege lijst");
+
+        let mut serializer = Serializer::new(String::new()).with_keywords(keywords);
+        vec![Some(1), None].serialize(&mut serializer)?;
+        assert_eq!(
+            serializer.writer,
+            "de lijst waar een element is 1 en nog element is niets"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_with_builder() -> Result<()> {
+        let builder = SerializerBuilder::new().delimiter('"');
+        let mut serializer = builder.build(String::new());
+        "say \"hi\"".serialize(&mut serializer)?;
+        assert_eq!(serializer.writer, r#""say \"hi\"""#);
+
+        let mut out = String::new();
+        crate::to_writer_with(&mut out, "string", &builder)?;
+        assert_eq!(out, r#""string""#);
+
+        let builder = builder.max_depth(0);
+        assert!(matches!(
+            crate::to_writer_with(&mut String::new(), &vec![1], &builder),
+            Err(Error::DepthLimitExceeded)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_primitive_map_keys() -> Result<()> {
+        let bools: BTreeMap<bool, u8> = vec![(true, 1), (false, 0)].into_iter().collect();
+        let string = to_string(&bools)?;
+        assert_eq!(string, "the object where false is 0 and true is 1");
+        assert_eq!(crate::from_str::<BTreeMap<bool, u8>>(&string)?, bools);
+
+        let ints: BTreeMap<i64, u8> = vec![(-1, 1), (2, 0)].into_iter().collect();
+        let string = to_string(&ints)?;
+        assert_eq!(string, "the object where -1 is 1 and 2 is 0");
+        assert_eq!(crate::from_str::<BTreeMap<i64, u8>>(&string)?, ints);
+
+        let nested: BTreeMap<i64, BTreeMap<bool, u8>> = vec![(1, bools)].into_iter().collect();
+        let string = to_string(&nested)?;
+        assert_eq!(
+            crate::from_str::<BTreeMap<i64, BTreeMap<bool, u8>>>(&string)?,
+            nested
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_bytes() -> Result<()> {
+        assert_eq!(encode_bytes(&[]), "");
+        assert_eq!(encode_bytes(&[0x00, 0x0f, 0xa0, 0xff]), "000fa0ff");
+        assert_eq!(to_string(&serde_bytes::Bytes::new(b"hi"))?, "`6869`");
         Ok(())
     }
 
@@ -775,6 +1272,165 @@ pub mod tests {
             to_string(&ExampleEnum::OtherVariant(true))?,
             "the `other variant` which is true"
         );
+
+        #[derive(Serialize, serde::Deserialize, Debug, PartialEq)]
+        enum E {
+            V(u64),
+            Nested(Vec<u64>),
+        }
+        for value in [E::V(1), E::Nested(vec![1, 2])] {
+            let string = to_string(&value)?;
+            assert_eq!(crate::from_str::<E>(&string)?, value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_terse() -> Result<()> {
+        #[derive(Serialize)]
+        struct User {
+            id: u8,
+            is_admin: bool,
+            tags: Vec<&'static str>,
+        }
+
+        fn terse<T: Serialize>(value: &T) -> Result<String> {
+            let mut serializer = Serializer::new(String::new()).with_terse(true);
+            value.serialize(&mut serializer)?;
+            Ok(serializer.writer)
+        }
+        assert_eq!(
+            terse(&vec![1, 2])?,
+            "list where an item is 1 and another item is 2"
+        );
+        assert_eq!(terse(&Vec::<u8>::new())?, "empty list");
+        assert_eq!(
+            terse(&User {
+                id: 1,
+                is_admin: true,
+                tags: vec!["a"],
+            })?,
+            "the `user` where `id` is 1 and `is admin` is true and `tags` is list where an item \
+             is `a`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_float_precision() -> Result<()> {
+        fn to_string_rounded<T: Serialize>(value: &T) -> Result<String> {
+            let mut out = String::new();
+            crate::to_writer_with(
+                &mut out,
+                value,
+                &SerializerBuilder::new().float_precision(2),
+            )?;
+            Ok(out)
+        }
+
+        assert_eq!(to_string(&(0.1 + 0.2))?, "0.30000000000000004");
+        assert_eq!(to_string_rounded(&(0.1 + 0.2))?, "0.3");
+        assert_eq!(to_string_rounded(&1.005f32)?, "1");
+        assert_eq!(to_string_rounded(&-2.345678)?, "-2.35");
+        assert_eq!(to_string_rounded(&0.001)?, "0");
+        assert_eq!(to_string_rounded(&1e300)?, to_string(&1e300)?);
+        assert_eq!(to_string_rounded(&7u8)?, "7");
+
+        let values = vec![0.5, 12.25, -3.75];
+        let string = to_string_rounded(&values)?;
+        assert_eq!(
+            string,
+            "the list where an item is 0.5 and another item is 12.25 and another item is -3.75"
+        );
+        assert_eq!(values, crate::from_str::<Vec<f64>>(&string)?);
+
+        let mut out = String::new();
+        0.123456.serialize(&mut Serializer::new(&mut out).with_float_precision(4))?;
+        assert_eq!(out, "0.1235");
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_pretty() -> Result<()> {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct User {
+            id: u32,
+            roles: Vec<Vec<String>>,
+            is_admin: bool,
+        }
+
+        let user = User {
+            id: 1,
+            roles: vec![vec!["read".to_string(), "write".to_string()], vec![]],
+            is_admin: false,
+        };
+        let pretty = crate::to_string_pretty(&user)?;
+        assert_eq!(
+            pretty,
+            "the `user` henceforth `the user` where the `id` is 1
+  and the `roles` is the list henceforth `the user roles list` where an item is the list where an item is `read`
+      and another item is `write`
+    and another item of `the user roles list` is the empty list
+  and `is admin` of `the user` is false"
+        );
+        assert_eq!(crate::from_str::<User>(&pretty)?, user);
+        assert_eq!(
+            crate::from_str::<User>(&to_string(&user)?)?,
+            crate::from_str::<User>(&pretty)?
+        );
+        assert_eq!(crate::to_string_pretty(&1)?, "1");
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_large_list() -> Result<()> {
+        let list: Vec<u16> = (0..10_000).collect();
+        let string = to_string(&list)?;
+        assert!(string.starts_with("the list where an item is 0 and another item is 1 and "));
+        assert!(string.ends_with(" and another item is 9999"));
+        assert_eq!(string.matches(" item is ").count(), 10_000);
+        assert_eq!(list, crate::from_str::<Vec<u16>>(&string)?);
+
+        let nested = vec![list.clone(), list];
+        let string = to_string(&nested)?;
+        assert!(string.starts_with(
+            "the list henceforth `the list` where an item is the list where an item is 0 and "
+        ));
+        assert!(string.contains(" and another item of `the list` is the list where an item is 0 "));
+        assert_eq!(nested, crate::from_str::<Vec<Vec<u16>>>(&string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_flatten() -> Result<()> {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Inner {
+            name: String,
+            nested: Vec<Vec<u8>>,
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Outer {
+            id: u32,
+            #[serde(flatten)]
+            inner: Inner,
+            is_last: bool,
+        }
+
+        let outer = Outer {
+            id: 1,
+            inner: Inner {
+                name: "name".to_string(),
+                nested: vec![vec![1], vec![2]],
+            },
+            is_last: true,
+        };
+        let string = to_string(&outer)?;
+        assert_eq!(
+            string,
+            r"the object henceforth `the object` where `id` is 1 and `name` is `name` and `nested` is the list henceforth `the object nested list` where an item is the list where an item is 1 and another item of `the object nested list` is the list where an item is 2 and `is_last` of `the object` is true"
+        );
+        assert_eq!(crate::from_str::<Outer>(&string)?, outer);
         Ok(())
     }
 
@@ -801,6 +1457,22 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn serialize_map_key_scope() -> Result<()> {
+        let mut map = BTreeMap::new();
+        map.insert("a`b", vec![vec![1u8], vec![2]]);
+        let string = to_string(&map)?;
+        assert_eq!(
+            string,
+            r"the object where `a\`b` is the list henceforth `the object a\`b list` where an item is the list where an item is 1 and another item of `the object a\`b list` is the list where an item is 2"
+        );
+        assert_eq!(
+            crate::from_str::<BTreeMap<String, Vec<Vec<u8>>>>(&string)?,
+            map.into_iter().map(|(k, v)| (k.to_string(), v)).collect()
+        );
+        Ok(())
+    }
+
     #[test]
     fn serialize_struct() -> Result<()> {
         #[derive(Serialize)]
@@ -808,7 +1480,7 @@ pub mod tests {
             id: i32,
             name: String,
             roles: Vec<String>,
-        };
+        }
 
         assert_eq!(to_string(&User { id: 1, name: "user".to_string(), roles: vec!["Admin".to_string()] })?, "the `user` where the `id` is 1 and the `name` is `user` and the `roles` is the list where an item is `Admin`");
         Ok(())
@@ -3,13 +3,22 @@
 #[cfg(feature = "std")]
 extern crate std as core;
 
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
 use core::fmt;
+use core::ops::Range;
 
-/// Represents either a float or an integer
+/// Represents either a float or an integer. Integers which do not fit in an `i64` are held in the
+/// 128 bit variants
 #[derive(Debug, PartialEq)]
 pub enum Number {
     Float(f64),
     Integer(i64),
+    Integer128(i128),
+    Unsigned128(u128),
 }
 
 /// Result of parsing a string
@@ -40,12 +49,23 @@ pub enum ParseError {
 /// parsed as well as the remaining unparsed string
 pub type ParseResult<'a, T> = Result<(usize, T, &'a str), ParseError>;
 
-/// Attempt to parse a `Parsed::Token`
-pub fn parse_token(src: &str) -> ParseResult<&str> {
+/// The period which may end a sentence directly after its last word, e.g. `is 1.`
+pub const PERIOD: &str = ".";
+
+/// Whether a period at the start of `src` ends a sentence, i.e. is followed by whitespace or
+/// the end of the input
+fn is_sentence_end(src: &str) -> bool {
+    src.strip_prefix(PERIOD)
+        .is_some_and(|rest| rest.chars().next().is_none_or(char::is_whitespace))
+}
+
+/// Attempt to parse a `Parsed::Token`. A period ending a sentence is not part of the token but
+/// left to be parsed as a token of its own
+pub fn parse_token(src: &str) -> ParseResult<'_, &str> {
     let mut t_start = None;
     let mut t_end = None;
     let mut end = None;
-    for (i, c) in src.chars().enumerate() {
+    for (i, c) in src.char_indices() {
         if t_start.is_none() {
             if !c.is_whitespace() {
                 t_start = Some(i);
@@ -68,12 +88,15 @@ pub fn parse_token(src: &str) -> ParseResult<&str> {
     }
 
     let t_start = t_start.unwrap();
-    if t_end.is_none() {
-        Ok((t_start, &src[t_start..], ""))
-    } else if end.is_none() {
-        Ok((t_start, &src[t_start..t_end.unwrap()], ""))
-    } else {
-        Ok((t_start, &src[t_start..t_end.unwrap()], &src[end.unwrap()..]))
+    let token_end = t_end.unwrap_or(src.len());
+    if token_end - t_start > PERIOD.len() && src[..token_end].ends_with(PERIOD) {
+        let period = token_end - PERIOD.len();
+        return Ok((t_start, &src[t_start..period], &src[period..]));
+    }
+    match (t_end, end) {
+        (None, _) => Ok((t_start, &src[t_start..], "")),
+        (Some(t_end), None) => Ok((t_start, &src[t_start..t_end], "")),
+        (Some(t_end), Some(end)) => Ok((t_start, &src[t_start..t_end], &src[end..])),
     }
 }
 
@@ -83,14 +106,14 @@ fn parse_delimited(
     start_char: char,
     end_char: char,
     escape_char: char,
-) -> ParseResult<&str> {
+) -> ParseResult<'_, &str> {
     let mut s_start = None;
     let mut s_end = None;
     let mut end = None;
     let mut was_start_char = false;
     let mut was_end_char = false;
     let mut was_escape_char = false;
-    for (i, c) in src.chars().enumerate() {
+    for (i, c) in src.char_indices() {
         if s_start.is_none() {
             if was_start_char {
                 s_start = Some(i);
@@ -108,7 +131,7 @@ fn parse_delimited(
             if !was_escape_char && c == end_char {
                 s_end = Some(i);
                 was_end_char = true;
-            } else if c == escape_char {
+            } else if !was_escape_char && c == escape_char {
                 was_escape_char = true;
                 continue;
             }
@@ -116,6 +139,10 @@ fn parse_delimited(
             continue;
         }
         if !c.is_whitespace() {
+            if was_end_char && is_sentence_end(&src[i..]) {
+                end = Some(i);
+                break;
+            }
             if was_end_char {
                 return Err(ParseError::ExpectedWhitespace(i));
             }
@@ -131,34 +158,136 @@ fn parse_delimited(
 
     let s_start = s_start.unwrap();
     let s_end = s_end.unwrap();
-    if end.is_none() {
-        Ok((s_start, &src[s_start..s_end], ""))
+    if let Some(end) = end {
+        Ok((s_start, &src[s_start..s_end], &src[end..]))
     } else {
-        Ok((s_start, &src[s_start..s_end], &src[end.unwrap()..]))
+        Ok((s_start, &src[s_start..s_end], ""))
     }
 }
 
+/// The default delimiter of strings
+pub const DEFAULT_DELIMITER: char = '`';
+
 /// Attempt to parse a `Parsed::String`
 #[inline]
-pub fn parse_string(src: &str) -> ParseResult<&str> {
-    parse_delimited(src, '`', '`', '\\')
+pub fn parse_string(src: &str) -> ParseResult<'_, &str> {
+    parse_string_with(src, DEFAULT_DELIMITER)
+}
+
+/// Attempt to parse a `Parsed::String` which is surrounded by `delimiter` instead of a backtick.
+/// Escaping is still done with `\\`
+///
+/// Like a token, a string may be directly followed by a period ending the sentence, which is
+/// left in the remaining input
+#[inline]
+pub fn parse_string_with(src: &str, delimiter: char) -> ParseResult<'_, &str> {
+    parse_delimited(src, delimiter, delimiter, '\\')
+}
+
+/// Remove the escaping of strings where `\\` escapes the next character, e.g. the delimiter or
+/// another `\\`. Only allocates when there is something to unescape
+///
+/// ```
+/// # use nl_parser::unescape_str;
+/// # use std::borrow::Cow;
+/// assert!(matches!(unescape_str("plain"), Cow::Borrowed("plain")));
+/// assert_eq!(unescape_str(r"a\`b\\c"), r"a`b\c");
+/// ```
+pub fn unescape_str(string: &str) -> Cow<'_, str> {
+    if !string.contains('\\') {
+        return Cow::Borrowed(string);
+    }
+    let mut out = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            out.extend(chars.next());
+        } else {
+            out.push(ch);
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Remove underscores used to group digits, e.g. `1_000`. Underscores are only allowed between
+/// two digits
+fn strip_digit_separators(token: &str) -> Option<String> {
+    let mut out = String::with_capacity(token.len());
+    let mut prev = None;
+    let mut chars = token.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '_' {
+            let next = chars.peek().copied();
+            if !prev.is_some_and(|c: char| c.is_ascii_digit())
+                || !next.is_some_and(|c: char| c.is_ascii_digit())
+            {
+                return None;
+            }
+        } else {
+            out.push(c);
+        }
+        prev = Some(c);
+    }
+    Some(out)
 }
 
-/// Attempt to parse a `Parsed::Number`
-pub fn parse_number(src: &str) -> ParseResult<Number> {
+/// Attempt to parse a `Parsed::Number`. Integers, floats (including scientific notation such as
+/// `1.5e-3`) and digits grouped with underscores such as `1_000` are accepted
+pub fn parse_number(src: &str) -> ParseResult<'_, Number> {
+    let (index, token, rest) = parse_number_token(src)?;
+    let token = token.as_ref();
+    let digits = token
+        .strip_prefix('-')
+        .or_else(|| token.strip_prefix('+'))
+        .unwrap_or(token);
+    let is_integer = !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+    if is_integer {
+        if let Ok(num) = token.parse::<i64>() {
+            return Ok((index, Number::Integer(num), rest));
+        } else if let Ok(num) = token.parse::<i128>() {
+            return Ok((index, Number::Integer128(num), rest));
+        } else if let Ok(num) = token.parse::<u128>() {
+            return Ok((index, Number::Unsigned128(num), rest));
+        }
+        // integers too large for any integer type fall back to a float
+    }
+    match token.parse::<f64>() {
+        Ok(num) => Ok((index, Number::Float(num), rest)),
+        Err(_) => Err(ParseError::InvalidNumber(index)),
+    }
+}
+
+/// Attempt to parse a number directly as an `f32`. This accepts the same numbers as
+/// `parse_number` but rounds once to the nearest `f32`, where parsing an `f64` and casting it may
+/// round twice and land on the wrong neighbour
+pub fn parse_f32(src: &str) -> ParseResult<'_, f32> {
+    let (index, token, rest) = parse_number_token(src)?;
+    match token.parse::<f32>() {
+        Ok(num) => Ok((index, num, rest)),
+        Err(_) => Err(ParseError::InvalidNumber(index)),
+    }
+}
+
+/// Parse the next token with any digit separators removed
+fn parse_number_token(src: &str) -> ParseResult<'_, Cow<'_, str>> {
     let (index, token, rest) = parse_token(src)?;
-    if let Ok(num) = token.parse() {
-        Ok((index, Number::Integer(num), rest))
-    } else if let Ok(num) = token.parse() {
-        Ok((index, Number::Float(num), rest))
+    if token.contains('_') {
+        let stripped = strip_digit_separators(token).ok_or(ParseError::InvalidNumber(index))?;
+        Ok((index, Cow::Owned(stripped), rest))
     } else {
-        return Err(ParseError::InvalidNumber(index));
+        Ok((index, Cow::Borrowed(token), rest))
     }
 }
 
 /// Attempt to parse a `Parsed`. Precedence is string, then number and then token
-pub fn parse_next(src: &str) -> ParseResult<Parsed> {
-    if let Ok((index, string, rest)) = parse_string(src) {
+#[inline]
+pub fn parse_next(src: &str) -> ParseResult<'_, Parsed<'_>> {
+    parse_next_with(src, DEFAULT_DELIMITER)
+}
+
+/// Attempt to parse a `Parsed` where strings are surrounded by `delimiter`
+pub fn parse_next_with(src: &str, delimiter: char) -> ParseResult<'_, Parsed<'_>> {
+    if let Ok((index, string, rest)) = parse_string_with(src, delimiter) {
         Ok((index, Parsed::Str(string), rest))
     } else if let Ok((index, num, rest)) = parse_number(src) {
         Ok((index, Parsed::Number(num), rest))
@@ -167,19 +296,150 @@ pub fn parse_next(src: &str) -> ParseResult<Parsed> {
     }
 }
 
+/// Attempt to parse a `Parsed` along with the byte range of `src` it was parsed from. The range
+/// excludes surrounding whitespace and includes the delimiters of a string
+#[inline]
+pub fn parse_next_spanned(src: &str) -> ParseResult<'_, (Range<usize>, Parsed<'_>)> {
+    parse_next_spanned_with(src, DEFAULT_DELIMITER)
+}
+
+/// Attempt to parse a `Parsed` along with its byte range where strings are surrounded by
+/// `delimiter`
+pub fn parse_next_spanned_with(
+    src: &str,
+    delimiter: char,
+) -> ParseResult<'_, (Range<usize>, Parsed<'_>)> {
+    if let Ok((index, string, rest)) = parse_string_with(src, delimiter) {
+        let start = index - delimiter.len_utf8();
+        let end = index + string.len() + delimiter.len_utf8();
+        return Ok((start, (start..end, Parsed::Str(string)), rest));
+    }
+    // numbers and tokens both span the next word
+    let (index, token, rest) = parse_token(src)?;
+    let parsed = match parse_number(src) {
+        Ok((_, num, _)) => Parsed::Number(num),
+        Err(_) => Parsed::Token(token),
+    };
+    Ok((index, (index..index + token.len(), parsed), rest))
+}
+
+/// The ordinals which are written as words, e.g. `the second item`
+pub const ORDINALS: [&str; 12] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth",
+    "eleventh", "twelfth",
+];
+
+/// Write the ordinal of a position starting at 1. Positions up to the twelfth are written as
+/// words and larger ones with a suffix
+///
+/// ```
+/// # use nl_parser::ordinal;
+/// assert_eq!(ordinal(2), "second");
+/// assert_eq!(ordinal(23), "23rd");
+/// ```
+pub fn ordinal(position: usize) -> Cow<'static, str> {
+    if let Some(word) = position.checked_sub(1).and_then(|i| ORDINALS.get(i)) {
+        return Cow::Borrowed(word);
+    }
+    let suffix = match (position % 10, position % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    Cow::Owned(format!("{}{}", position, suffix))
+}
+
+/// Read an ordinal written by `ordinal` back into a position starting at 1. Ordinals are matched
+/// case-insensitively
+///
+/// ```
+/// # use nl_parser::parse_ordinal;
+/// assert_eq!(parse_ordinal("Second"), Some(2));
+/// assert_eq!(parse_ordinal("23rd"), Some(23));
+/// assert_eq!(parse_ordinal("item"), None);
+/// ```
+pub fn parse_ordinal(word: &str) -> Option<usize> {
+    if let Some(i) = ORDINALS
+        .iter()
+        .position(|ordinal| ordinal.eq_ignore_ascii_case(word))
+    {
+        return Some(i + 1);
+    }
+    if !matches!(word.chars().next()?, '1'..='9') {
+        return None;
+    }
+    if word.len() < 3 || !word.is_char_boundary(word.len() - 2) {
+        return None;
+    }
+    let (digits, suffix) = word.split_at(word.len() - 2);
+    if !["st", "nd", "rd", "th"]
+        .iter()
+        .any(|ordinal_suffix| ordinal_suffix.eq_ignore_ascii_case(suffix))
+        || !digits.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Convert a byte index of `src` into a line and column pair. Both start at 1
+pub fn line_column(src: &str, index: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for (i, c) in src.char_indices() {
+        if i >= index {
+            break;
+        }
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+impl ParseError {
+    /// The byte index at which the error occured if there is one
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            ParseError::UnexpectedEof => None,
+            ParseError::InvalidString(i)
+            | ParseError::InvalidNumber(i)
+            | ParseError::ExpectedWhitespace(i) => Some(*i),
+        }
+    }
+
+    /// Shift the index of the error by `offset`. Useful when the parsed string was a slice of a
+    /// larger source
+    pub fn offset(self, offset: usize) -> Self {
+        match self {
+            ParseError::UnexpectedEof => ParseError::UnexpectedEof,
+            ParseError::InvalidString(i) => ParseError::InvalidString(i + offset),
+            ParseError::InvalidNumber(i) => ParseError::InvalidNumber(i + offset),
+            ParseError::ExpectedWhitespace(i) => ParseError::ExpectedWhitespace(i + offset),
+        }
+    }
+
+    /// A description of the error without the position
+    pub fn message(&self) -> &'static str {
+        match self {
+            ParseError::UnexpectedEof => "unexpected end of file",
+            ParseError::InvalidString(_) => "invalid string",
+            ParseError::InvalidNumber(_) => "invalid number",
+            ParseError::ExpectedWhitespace(_) => "expected whitespace",
+        }
+    }
+}
+
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            ParseError::UnexpectedEof => f.write_str("unexpected end of file"),
-            ParseError::InvalidString(i) => {
-                f.write_fmt(format_args!("invalid string at character {}", i + 1))
-            }
-            ParseError::InvalidNumber(i) => {
-                f.write_fmt(format_args!("invalid number at character {}", i + 1))
-            }
-            ParseError::ExpectedWhitespace(i) => {
-                f.write_fmt(format_args!("expected whitespace at character {}", i + 1))
-            }
+        match self.index() {
+            Some(i) => f.write_fmt(format_args!("{} at byte {}", self.message(), i)),
+            None => f.write_str(self.message()),
         }
     }
 }
@@ -193,6 +453,26 @@ impl Eq for Number {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn ordinals() {
+        assert_eq!(ordinal(1), "first");
+        assert_eq!(ordinal(12), "twelfth");
+        assert_eq!(ordinal(13), "13th");
+        assert_eq!(ordinal(21), "21st");
+        assert_eq!(ordinal(22), "22nd");
+        assert_eq!(ordinal(111), "111th");
+        for position in 1..200 {
+            assert_eq!(parse_ordinal(&ordinal(position)), Some(position));
+        }
+        assert_eq!(parse_ordinal("FIRST"), Some(1));
+        assert_eq!(parse_ordinal("2ND"), Some(2));
+        assert_eq!(parse_ordinal("0th"), None);
+        assert_eq!(parse_ordinal("1"), None);
+        assert_eq!(parse_ordinal("1-st"), None);
+        assert_eq!(parse_ordinal("th"), None);
+        assert_eq!(parse_ordinal(""), None);
+    }
+
     #[test]
     fn parse_tokens() -> Result<(), ParseError> {
         assert_eq!((0, "a", ""), parse_token("a")?);
@@ -208,6 +488,11 @@ mod tests {
         assert_eq!((1, "the", "list"), parse_token(" the list")?);
         assert_eq!((0, "the", "list"), parse_token("the   list")?);
         assert_eq!((3, "the", "list"), parse_token("   the   list")?);
+        assert_eq!((0, "list", ". the"), parse_token("list. the")?);
+        assert_eq!((0, "1.5", "."), parse_token("1.5.")?);
+        assert_eq!((0, ".", ""), parse_token(".")?);
+        assert_eq!((1, ".", "the"), parse_token(" . the")?);
+        assert_eq!((0, "e.g", "."), parse_token("e.g.")?);
         assert_eq!((0, "the", "list "), parse_token("the list ")?);
         assert_eq!((1, "the", "list "), parse_token(" the list ")?);
         assert_eq!((0, "the", "list "), parse_token("the   list ")?);
@@ -218,6 +503,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_non_ascii_tokens() -> Result<(), ParseError> {
+        assert_eq!((0, "café", "au lait"), parse_token("café au lait")?);
+        assert_eq!((1, "naïve", ""), parse_token(" naïve ")?);
+        assert_eq!((3, "the", "list"), parse_token("\u{3000}the\u{3000}list")?);
+        assert_eq!((0, "日本", "語 "), parse_token("日本 語 ")?);
+        assert_eq!((0, Parsed::Token("über"), "`a`"), parse_next("über `a`")?);
+        assert!(matches!(
+            parse_number("é"),
+            Err(ParseError::InvalidNumber(0))
+        ));
+        assert!(matches!(
+            parse_token("\u{3000}"),
+            Err(ParseError::UnexpectedEof)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn parse_strings() -> Result<(), ParseError> {
         assert_eq!((1, "", ""), parse_string("``")?);
@@ -269,6 +572,9 @@ mod tests {
         );
 
         assert_eq!((1, "hello", "`world`"), parse_string("`hello` `world`")?);
+        assert_eq!((1, r"a\`b", ""), parse_string(r"`a\`b`")?);
+        assert_eq!((1, r"a\\", "b"), parse_string(r"`a\\` b")?);
+        assert_eq!((1, r"a\\\`", ""), parse_string(r"`a\\\``")?);
 
         assert!(matches!(parse_string(""), Err(ParseError::UnexpectedEof)));
         assert!(matches!(parse_string(" "), Err(ParseError::UnexpectedEof)));
@@ -314,6 +620,18 @@ mod tests {
         assert_eq!((1, r#"\`"#, ""), parse_string(r#"`\`` "#)?);
         assert_eq!((2, r#"\`"#, ""), parse_string(r#" `\`` "#)?);
 
+        assert_eq!((1, "a", ". b"), parse_string("`a`. b")?);
+        assert_eq!((1, "a", "."), parse_string("`a`.")?);
+        assert_eq!((1, "a.", ""), parse_string("`a.`")?);
+        assert!(matches!(
+            parse_string("`a`.b"),
+            Err(ParseError::ExpectedWhitespace(3))
+        ));
+        assert!(matches!(
+            parse_string("`a`.."),
+            Err(ParseError::ExpectedWhitespace(3))
+        ));
+
         assert!(matches!(
             parse_string("``a"),
             Err(ParseError::ExpectedWhitespace(2))
@@ -330,6 +648,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_spans() -> Result<(), ParseError> {
+        let (_, (span, parsed), rest) = parse_next_spanned("  the `quoted string`")?;
+        assert_eq!((span, parsed), (2..5, Parsed::Token("the")));
+        let (_, (span, parsed), _) = parse_next_spanned(rest)?;
+        assert_eq!(&rest[span], "`quoted string`");
+        assert_eq!(parsed, Parsed::Str("quoted string"));
+
+        let src = " \t`quoted` 1.5e3  `a\\`b` word";
+        let mut rest = src;
+        let mut spans = Vec::new();
+        let mut offset = 0;
+        while !rest.is_empty() {
+            let (_, (span, _), next) = parse_next_spanned(rest)?;
+            spans.push(&src[offset + span.start..offset + span.end]);
+            offset += rest.len() - next.len();
+            rest = next;
+        }
+        assert_eq!(spans, ["`quoted`", "1.5e3", "`a\\`b`", "word"]);
+
+        let (_, (span, parsed), _) = parse_next_spanned_with(" «ü»", '«')?;
+        assert_eq!(span, 1..7);
+        assert_eq!(parsed, Parsed::Token("«ü»"));
+        let (_, (span, parsed), _) = parse_next_spanned_with(" ßüß ", 'ß')?;
+        assert_eq!(span, 1..7);
+        assert_eq!(parsed, Parsed::Str("ü"));
+        let (index, (span, parsed), _) = parse_next_spanned(" 42")?;
+        assert_eq!(
+            (index, span, parsed),
+            (1, 1..3, Parsed::Number(Number::Integer(42)))
+        );
+        assert!(matches!(
+            parse_next_spanned("  "),
+            Err(ParseError::UnexpectedEof)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_f32s() -> Result<(), ParseError> {
+        assert_eq!((0, 1.5, ""), parse_f32("1.5")?);
+        assert_eq!((0, -3., ""), parse_f32("-3")?);
+        assert_eq!((0, 1000.25, ""), parse_f32("1_000.25")?);
+        assert_eq!((1, 16777216., "rest"), parse_f32(" 16777217 rest")?);
+        assert!(matches!(parse_f32("a"), Err(ParseError::InvalidNumber(0))));
+        assert!(matches!(parse_f32("1_"), Err(ParseError::InvalidNumber(0))));
+
+        // just above the midpoint of 1 and the next f32, which an f64 rounds onto exactly
+        let src = "1.00000005960464477550";
+        assert_eq!((0, 1. + f32::EPSILON, ""), parse_f32(src)?);
+        match parse_number(src)? {
+            (_, Number::Float(num), _) => assert_eq!(num as f32, 1.),
+            res => panic!("unexpected result {:?}", res),
+        }
+        Ok(())
+    }
+
     #[test]
     fn parse_numbers() -> Result<(), ParseError> {
         assert_eq!((0, Number::Integer(0), ""), parse_number("0")?);
@@ -345,6 +720,66 @@ mod tests {
 
         assert_eq!((1, Number::Integer(1), ""), parse_number(" 1")?);
         assert_eq!((0, Number::Integer(1), ""), parse_number("1 ")?);
+
+        assert_eq!((0, Number::Integer(3), ""), parse_number("3")?);
+        assert_eq!((0, Number::Integer(3), ""), parse_number("+3")?);
+        assert_eq!((0, Number::Float(3.), ""), parse_number("3.0")?);
+        assert_eq!((0, Number::Float(300.), ""), parse_number("3e2")?);
+        // a trailing period ends the sentence rather than the number
+        assert_eq!((0, Number::Integer(3), "."), parse_number("3.")?);
+        assert!(matches!(
+            parse_number("-"),
+            Err(ParseError::InvalidNumber(0))
+        ));
+        assert_eq!((0, Number::Float(1e6), ""), parse_number("1e6")?);
+        assert_eq!((0, Number::Float(-2.5e3), ""), parse_number("-2.5E3")?);
+        assert_eq!((0, Number::Float(1.5e-3), ""), parse_number("1.5e-3")?);
+        assert_eq!(
+            (0, Number::Integer(1_000_000), ""),
+            parse_number("1_000_000")?
+        );
+        assert_eq!((0, Number::Float(-1_000.5), ""), parse_number("-1_000.5")?);
+        assert!(matches!(
+            parse_number("_1"),
+            Err(ParseError::InvalidNumber(0))
+        ));
+        assert!(matches!(
+            parse_number("1_"),
+            Err(ParseError::InvalidNumber(0))
+        ));
+        assert!(matches!(
+            parse_number("1__0"),
+            Err(ParseError::InvalidNumber(0))
+        ));
+        assert!(matches!(
+            parse_number("snake_case"),
+            Err(ParseError::InvalidNumber(0))
+        ));
+
+        assert_eq!(
+            (0, Number::Integer(i64::MAX), ""),
+            parse_number("9223372036854775807")?
+        );
+        assert_eq!(
+            (0, Number::Integer128(i64::MAX as i128 + 1), ""),
+            parse_number("9223372036854775808")?
+        );
+        assert_eq!(
+            (0, Number::Integer128(i128::MIN), ""),
+            parse_number("-170141183460469231731687303715884105728")?
+        );
+        assert_eq!(
+            (0, Number::Unsigned128(u128::MAX), ""),
+            parse_number("340282366920938463463374607431768211455")?
+        );
+        assert_eq!(
+            (
+                0,
+                Number::Float(340282366920938463463374607431768211456.),
+                ""
+            ),
+            parse_number("340282366920938463463374607431768211456")?
+        );
         assert_eq!((1, Number::Integer(1), ""), parse_number(" 1 ")?);
         assert_eq!((1, Number::Float(1.), ""), parse_number(" 1.0")?);
         assert_eq!((0, Number::Float(1.), ""), parse_number("1.0 ")?);
@@ -373,6 +808,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn line_columns() {
+        assert_eq!((1, 1), line_column("", 0));
+        assert_eq!((1, 1), line_column("the list", 0));
+        assert_eq!((1, 5), line_column("the list", 4));
+        assert_eq!((2, 1), line_column("the list\nwhere", 9));
+        assert_eq!((3, 3), line_column("the\nlist\nwhere", 11));
+        assert_eq!((1, 3), line_column("éé`", 4));
+    }
+
+    #[test]
+    fn error_positions() {
+        let err = ParseError::InvalidString(2).offset(3);
+        assert_eq!(Some(5), err.index());
+        assert_eq!("invalid string at byte 5", err.to_string());
+        assert_eq!(None, ParseError::UnexpectedEof.offset(3).index());
+        assert_eq!(
+            "unexpected end of file",
+            ParseError::UnexpectedEof.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_non_ascii_strings() -> Result<(), ParseError> {
+        assert_eq!((1, "héllo", "token"), parse_string("`héllo` token")?);
+        assert_eq!((4, "wörld", ""), parse_string("\u{3000}`wörld`")?);
+        assert_eq!((1, "日本", "`語`"), parse_string("`日本` `語`")?);
+        assert_eq!((1, r"é\`", ""), parse_string(r"`é\``")?);
+        assert_eq!(
+            (1, Parsed::Str("héllo"), "token"),
+            parse_next("`héllo` token")?
+        );
+        assert!(matches!(
+            parse_string("`é`a"),
+            Err(ParseError::ExpectedWhitespace(4))
+        ));
+        assert!(matches!(
+            parse_string("é`a`"),
+            Err(ParseError::InvalidString(0))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_strings_with_delimiter() -> Result<(), ParseError> {
+        assert_eq!((1, "a", ""), parse_string_with("'a'", '\'')?);
+        assert_eq!(
+            (2, "hello, world", "token"),
+            parse_string_with(r#" "hello, world" token"#, '"')?
+        );
+        assert_eq!((1, r"it\'s", ""), parse_string_with(r"'it\'s'", '\'')?);
+        assert_eq!((1, "`a`", ""), parse_string_with("'`a`'", '\'')?);
+        assert!(matches!(
+            parse_string_with("`a`", '\''),
+            Err(ParseError::InvalidString(0))
+        ));
+        assert_eq!((1, Parsed::Str("a"), "b"), parse_next_with("'a' b", '\'')?);
+        assert_eq!((0, Parsed::Token("`a`"), ""), parse_next_with("`a`", '\'')?);
+        Ok(())
+    }
+
     #[test]
     fn parse_nexts() -> Result<(), ParseError> {
         assert_eq!(
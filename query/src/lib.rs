@@ -1,6 +1,6 @@
 //! A representation of querying an object by either a key or an index. Normally an type implements
 //! `AccessNext` and `AccessNextMut`. The `json` feature will implement this for the
-//! `serde_json::Value` type
+//! `serde_json::Value` type and the `toml` feature for the `toml::Value` type
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "std")]
@@ -8,9 +8,22 @@ extern crate std as core;
 
 extern crate alloc;
 
-use alloc::borrow::Cow;
+use alloc::borrow::{Borrow, Cow};
+use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
 use core::iter;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
+
+mod path;
+
+pub use path::{parse, ParseError};
 
 /// Either a key or an index query
 #[derive(Debug, PartialEq, Eq)]
@@ -34,7 +47,7 @@ pub enum SetResult<T> {
 
 impl Query<'static> {
     /// Create an index query from the front
-    pub fn index(index: usize) -> Self {
+    pub const fn index(index: usize) -> Self {
         Query::Index {
             index,
             from_last: false,
@@ -42,13 +55,23 @@ impl Query<'static> {
     }
 
     /// Create an index query from the back
-    pub fn index_from_last(index: usize) -> Self {
+    pub const fn index_from_last(index: usize) -> Self {
         Query::Index {
             index,
             from_last: true,
         }
     }
 
+    /// Create an index query for the first item, the same as `Query::index(0)`
+    pub const fn first() -> Self {
+        Self::index(0)
+    }
+
+    /// Create an index query for the last item, the same as `Query::index_from_last(0)`
+    pub const fn last() -> Self {
+        Self::index_from_last(0)
+    }
+
     /// Create a owned key query
     pub fn key_owned(key: String) -> Self {
         Query::Key(Cow::Owned(key))
@@ -56,8 +79,18 @@ impl Query<'static> {
 }
 
 impl<'a> Query<'a> {
-    /// Create a borrowed key query
-    pub fn key(key: &'a str) -> Self {
+    /// Create a borrowed key query. Together with the index constructors this can build a query
+    /// path in a constant, so the path itself needs no allocation
+    ///
+    /// ```
+    /// # #[cfg(feature = "json")] {
+    /// # use object_query::{Access, Query};
+    /// const NAME: &[Query<'static>] = &[Query::key("users"), Query::first(), Query::key("name")];
+    /// let value = serde_json::json!({"users": [{"name": "rob"}]});
+    /// assert_eq!(value.access(NAME), Some(&serde_json::json!("rob")));
+    /// # }
+    /// ```
+    pub const fn key(key: &'a str) -> Self {
         Query::Key(Cow::Borrowed(key))
     }
 
@@ -69,20 +102,25 @@ impl<'a> Query<'a> {
         }
     }
 
+    /// Is an index query from the front
+    pub fn is_index_from_front(&self) -> bool {
+        matches!(
+            self,
+            Query::Index {
+                from_last: false,
+                ..
+            }
+        )
+    }
+
     /// Is a key query
     pub fn is_key(&self) -> bool {
-        match self {
-            Query::Key(_) => true,
-            _ => false,
-        }
+        matches!(self, Query::Key(_))
     }
 
     /// Is an index query
     pub fn is_index(&self) -> bool {
-        match self {
-            Query::Index { .. } => true,
-            _ => false,
-        }
+        matches!(self, Query::Index { .. })
     }
 
     /// Return the string reference if it is a key query
@@ -106,6 +144,14 @@ impl<'a> Query<'a> {
         }
     }
 
+    /// Returns the raw index and whether it is counted from the back if it is an index query
+    pub fn as_usize_index(&self) -> Option<(usize, bool)> {
+        match self {
+            Query::Index { index, from_last } => Some((*index, *from_last)),
+            _ => None,
+        }
+    }
+
     /// An alternative to the `std::borrow::ToOwned` method
     pub fn to_owned(&self) -> Query<'static> {
         match self {
@@ -124,6 +170,66 @@ impl<'a> Clone for Query<'a> {
     }
 }
 
+/// A key query equals its key. Index queries never equal a string
+///
+/// ```
+/// # use object_query::Query;
+/// assert!(Query::key("name") == "name");
+/// assert!(Query::index(0) != "0");
+/// ```
+impl<'a> PartialEq<str> for Query<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_key() == Some(other)
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for Query<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.as_key() == Some(*other)
+    }
+}
+
+/// Queries are ordered with all keys before all indices. Keys are ordered lexicographically and
+/// indices by their `as_index` value, so indices from the back come before indices from the front
+///
+/// ```
+/// # use object_query::Query;
+/// let mut queries = vec![Query::index(1), Query::last(), Query::key("b"), Query::first()];
+/// queries.sort();
+/// assert_eq!(queries, [Query::key("b"), Query::last(), Query::first(), Query::index(1)]);
+/// ```
+impl<'a> Ord for Query<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Query::Key(a), Query::Key(b)) => a.cmp(b),
+            (Query::Key(_), Query::Index { .. }) => Ordering::Less,
+            (Query::Index { .. }, Query::Key(_)) => Ordering::Greater,
+            (
+                Query::Index {
+                    index: a,
+                    from_last: a_from_last,
+                },
+                Query::Index {
+                    index: b,
+                    from_last: b_from_last,
+                },
+            ) => match (a_from_last, b_from_last) {
+                // compared without `as_index` so indices beyond `i64::MAX` stay ordered
+                (true, true) => b.cmp(a),
+                (false, false) => a.cmp(b),
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+            },
+        }
+    }
+}
+
+impl<'a> PartialOrd for Query<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Describes how to access query
 pub trait AccessNext<T = Self> {
     fn access_next<'a>(&self, query: &Query<'a>) -> Option<&T>;
@@ -142,9 +248,34 @@ pub trait AccessNextOwned<T = Self>: Sized {
 /// An easily implementable trait to acess a list of queries
 pub trait Access: AccessNext + Sized {
     fn access<'a, I: IntoIterator<Item = &'a Query<'a>>>(&self, queries: I) -> Option<&Self> {
-        queries.into_iter().fold(Some(self), |res, query| {
-            res.and_then(|res| res.access_next(query))
-        })
+        queries
+            .into_iter()
+            .try_fold(self, |res, query| res.access_next(query))
+    }
+
+    /// Like `access` but when a query can't be resolved returns its position in `queries` along
+    /// with the query itself
+    ///
+    /// ```
+    /// # #[cfg(feature = "json")] {
+    /// # use object_query::{query, Access, Query};
+    /// let value = serde_json::json!({"users": [{"name": "rob"}]});
+    /// assert_eq!(
+    ///     value.access_verbose(query!["users", 1, "name"]),
+    ///     Err((1, &Query::index(1)))
+    /// );
+    /// # }
+    /// ```
+    fn access_verbose<'a, I: IntoIterator<Item = &'a Query<'a>>>(
+        &self,
+        queries: I,
+    ) -> Result<&Self, (usize, &'a Query<'a>)> {
+        queries
+            .into_iter()
+            .enumerate()
+            .try_fold(self, |res, (index, query)| {
+                res.access_next(query).ok_or((index, query))
+            })
     }
 }
 
@@ -154,18 +285,18 @@ pub trait AccessMut: AccessNextMut + Sized {
         &mut self,
         queries: I,
     ) -> Option<&mut Self> {
-        queries.into_iter().fold(Some(self), |res, query| {
-            res.and_then(|res| res.access_next_mut(query))
-        })
+        queries
+            .into_iter()
+            .try_fold(self, |res, query| res.access_next_mut(query))
     }
 }
 
 /// An easily implementable trait to acess a list of queries on an owned item
 pub trait AccessOwned: AccessNextOwned {
     fn access_owned<'a, I: IntoIterator<Item = &'a Query<'a>>>(self, queries: I) -> Option<Self> {
-        queries.into_iter().fold(Some(self), |res, query| {
-            res.and_then(|res| res.access_next_owned(query))
-        })
+        queries
+            .into_iter()
+            .try_fold(self, |res, query| res.access_next_owned(query))
     }
 }
 
@@ -200,15 +331,12 @@ where
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.iter.next();
-        if next.is_none() {
-            return None;
-        }
+        let next = self.iter.next()?;
         if self.iter.peek().is_none() {
-            self.last = next;
+            self.last = Some(next);
             return None;
         }
-        next
+        Some(next)
     }
 }
 
@@ -235,6 +363,64 @@ pub trait QuerySet: QuerySetItem + AccessMut {
     }
 }
 
+/// Describe how to create an empty container which can be queried by a query
+pub trait QueryCreateItem: Sized {
+    fn query_create_item<'a>(query: &Query<'a>) -> Self;
+}
+
+/// An easily implementable trait to set a value from a list of queries on a mutable item,
+/// creating the containers missing along the way. A missing container is created empty with
+/// `QueryCreateItem` depending on the query which follows it. Existing values are never replaced
+/// by a container, so querying into a value which can't hold the next query is `NotSet`. Containers
+/// created before a query fails are kept
+pub trait QuerySetCreate: QuerySet + QueryCreateItem {
+    fn query_set_create<'a, I: IntoIterator<Item = &'a Query<'a>>>(
+        &mut self,
+        queries: I,
+        val: Self,
+    ) -> SetResult<Self> {
+        let mut queries = queries.into_iter().peekable();
+        let mut item = self;
+        while let Some(query) = queries.next() {
+            let next = match queries.peek() {
+                Some(next) => next,
+                None => return item.query_set_item(query, val),
+            };
+            if item.access_next_mut(query).is_none() {
+                let container = Self::query_create_item(next);
+                if let SetResult::NotSet = item.query_set_item(query, container) {
+                    return SetResult::NotSet;
+                }
+            }
+            item = match item.access_next_mut(query) {
+                Some(item) => item,
+                None => return SetResult::NotSet,
+            };
+        }
+        // implies empty query
+        SetResult::NotSet
+    }
+}
+
+/// Describe how to remove a value from a query
+pub trait QueryRemoveItem: Sized {
+    fn query_remove_item<'a>(&mut self, query: &Query<'a>) -> Option<Self>;
+}
+
+/// An easily implementable trait to remove a value from a list of queries on a mutable item
+pub trait QueryRemove: QueryRemoveItem + AccessMut {
+    fn query_remove<'a, I: IntoIterator<Item = &'a Query<'a>>>(
+        &mut self,
+        queries: I,
+    ) -> Option<Self> {
+        let mut iter = SkipLastIter::new(queries.into_iter());
+        let item = self.access_mut(&mut iter)?;
+        // an empty query has nothing to remove
+        let last = iter.last?;
+        item.query_remove_item(last)
+    }
+}
+
 impl From<usize> for Query<'static> {
     fn from(index: usize) -> Self {
         Self::Index {
@@ -244,11 +430,20 @@ impl From<usize> for Query<'static> {
     }
 }
 
+/// Non-negative integers index from the front and negative integers from the back, so `-1` is
+/// the last item
+///
+/// | integer | query                       |
+/// |---------|-----------------------------|
+/// | `0`     | `Query::first()`            |
+/// | `2`     | `Query::index(2)`           |
+/// | `-1`    | `Query::last()`             |
+/// | `-3`    | `Query::index_from_last(2)` |
 impl From<isize> for Query<'static> {
     fn from(index: isize) -> Self {
         if index.is_negative() {
             Self::Index {
-                index: index.abs() as usize - 1,
+                index: index.unsigned_abs() - 1,
                 from_last: true,
             }
         } else {
@@ -320,6 +515,248 @@ impl<'a> From<&'a str> for Query<'a> {
     }
 }
 
+/// Resolve an index query to a position in a list of `len` items
+fn list_index(query: &Query<'_>, len: usize) -> Option<usize> {
+    match query {
+        Query::Index {
+            index,
+            from_last: true,
+        } => len.checked_sub(1)?.checked_sub(*index),
+        Query::Index {
+            index,
+            from_last: false,
+        } => Some(*index),
+        Query::Key(_) => None,
+    }
+}
+
+/// Lists resolve an index query to the item at that position, counting from the end for
+/// `from_last` queries. Key queries always miss
+///
+/// ```
+/// # use object_query::{AccessNext, Query};
+/// let list = vec![1, 2, 3];
+/// assert_eq!(list.access_next(&Query::index_from_last(0)), Some(&3));
+/// assert_eq!(list.access_next(&Query::key("first")), None);
+/// ```
+impl<T> AccessNext<T> for [T] {
+    fn access_next<'a>(&self, query: &Query<'a>) -> Option<&T> {
+        self.get(list_index(query, self.len())?)
+    }
+}
+
+impl<T> AccessNextMut<T> for [T] {
+    fn access_next_mut<'a>(&mut self, query: &Query<'a>) -> Option<&mut T> {
+        let index = list_index(query, self.len())?;
+        self.get_mut(index)
+    }
+}
+
+impl<T, const N: usize> AccessNext<T> for [T; N] {
+    fn access_next<'a>(&self, query: &Query<'a>) -> Option<&T> {
+        self[..].access_next(query)
+    }
+}
+
+impl<T, const N: usize> AccessNextMut<T> for [T; N] {
+    fn access_next_mut<'a>(&mut self, query: &Query<'a>) -> Option<&mut T> {
+        self[..].access_next_mut(query)
+    }
+}
+
+impl<T> AccessNext<T> for Vec<T> {
+    fn access_next<'a>(&self, query: &Query<'a>) -> Option<&T> {
+        self.as_slice().access_next(query)
+    }
+}
+
+impl<T> AccessNextMut<T> for Vec<T> {
+    fn access_next_mut<'a>(&mut self, query: &Query<'a>) -> Option<&mut T> {
+        self.as_mut_slice().access_next_mut(query)
+    }
+}
+
+impl<T> AccessNextOwned<T> for Vec<T> {
+    fn access_next_owned<'a>(self, query: &Query<'a>) -> Option<T> {
+        let index = list_index(query, self.len())?;
+        self.into_iter().nth(index)
+    }
+}
+
+/// Maps resolve a key query by looking up the key. Index queries always miss. As the values are a
+/// different type to the map, only the first query can be resolved by the map. If the value type
+/// implements `Access` the rest of the queries can be passed on to it
+///
+/// ```
+/// # use object_query::{query, AccessNext};
+/// # use std::collections::BTreeMap;
+/// let mut user = BTreeMap::new();
+/// user.insert("name", "rob");
+/// let mut map = BTreeMap::new();
+/// map.insert("user", user);
+/// let path = query!["user", "name"];
+/// let name = map.access_next(&path[0]).and_then(|user| user.access_next(&path[1]));
+/// assert_eq!(name, Some(&"rob"));
+/// ```
+impl<K, V> AccessNext<V> for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+{
+    fn access_next<'a>(&self, query: &Query<'a>) -> Option<&V> {
+        match query {
+            Query::Index { .. } => None,
+            Query::Key(key) => self.get(key.as_ref()),
+        }
+    }
+}
+
+impl<K, V> AccessNextMut<V> for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+{
+    fn access_next_mut<'a>(&mut self, query: &Query<'a>) -> Option<&mut V> {
+        match query {
+            Query::Index { .. } => None,
+            Query::Key(key) => self.get_mut(key.as_ref()),
+        }
+    }
+}
+
+impl<K, V> AccessNextOwned<V> for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+{
+    fn access_next_owned<'a>(mut self, query: &Query<'a>) -> Option<V> {
+        match query {
+            Query::Index { .. } => None,
+            Query::Key(key) => self.remove(key.as_ref()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> AccessNext<V> for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    S: BuildHasher,
+{
+    fn access_next<'a>(&self, query: &Query<'a>) -> Option<&V> {
+        match query {
+            Query::Index { .. } => None,
+            Query::Key(key) => self.get(key.as_ref()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> AccessNextMut<V> for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    S: BuildHasher,
+{
+    fn access_next_mut<'a>(&mut self, query: &Query<'a>) -> Option<&mut V> {
+        match query {
+            Query::Index { .. } => None,
+            Query::Key(key) => self.get_mut(key.as_ref()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> AccessNextOwned<V> for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    S: BuildHasher,
+{
+    fn access_next_owned<'a>(mut self, query: &Query<'a>) -> Option<V> {
+        match query {
+            Query::Index { .. } => None,
+            Query::Key(key) => self.remove(key.as_ref()),
+        }
+    }
+}
+
+/// Index queries are serialized as an integer which is negative for an index from the back, as in
+/// `as_index`. Key queries are serialized as a string
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for Query<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Query::Index {
+                index,
+                from_last: false,
+            } => serializer.serialize_u64(*index as u64),
+            Query::Index {
+                index,
+                from_last: true,
+            } => {
+                let index = i64::try_from(*index)
+                    .ok()
+                    .and_then(|index| index.checked_add(1))
+                    .map(|index| -index)
+                    .ok_or_else(|| serde::ser::Error::custom("index out of range"))?;
+                serializer.serialize_i64(index)
+            }
+            Query::Key(key) => serializer.serialize_str(key),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct QueryVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for QueryVisitor {
+    type Value = Query<'static>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("an integer index or a string key")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let index = isize::try_from(v).map_err(|_| E::custom("index out of range"))?;
+        Ok(index.into())
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let index = usize::try_from(v).map_err(|_| E::custom("index out of range"))?;
+        Ok(index.into())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Query::key_owned(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Query::key_owned(v))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a> serde::Deserialize<'de> for Query<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(QueryVisitor)
+    }
+}
+
 #[cfg(feature = "json")]
 impl AccessNext for serde_json::Value {
     fn access_next<'a>(&self, query: &Query<'a>) -> Option<&Self> {
@@ -473,20 +910,477 @@ impl QuerySetItem for serde_json::Value {
 #[macro_export]
 macro_rules! query {
     ($($item:expr),*) => {
-        &[$($crate::Query::from(($item))),*]
+        &[$($crate::Query::from($item)),*]
     }
 }
 
 #[cfg(feature = "json")]
 impl QuerySet for serde_json::Value {}
 
+#[cfg(feature = "json")]
+impl QueryCreateItem for serde_json::Value {
+    fn query_create_item<'a>(query: &Query<'a>) -> Self {
+        match query {
+            Query::Index { .. } => serde_json::Value::Array(Vec::new()),
+            Query::Key(_) => serde_json::Value::Object(serde_json::Map::new()),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl QuerySetCreate for serde_json::Value {}
+
+#[cfg(feature = "json")]
+impl QueryRemoveItem for serde_json::Value {
+    fn query_remove_item<'a>(&mut self, query: &Query<'a>) -> Option<Self> {
+        match self {
+            serde_json::Value::Null => None,
+            serde_json::Value::Bool(_) => None,
+            serde_json::Value::Number(_) => None,
+            serde_json::Value::String(_) => None,
+            serde_json::Value::Array(array) => match query {
+                Query::Index { index, from_last } => {
+                    if *index >= array.len() {
+                        return None;
+                    }
+                    let index = if *from_last {
+                        array.len() - 1 - index
+                    } else {
+                        *index
+                    };
+                    Some(array.remove(index))
+                }
+                Query::Key(_) => None,
+            },
+            serde_json::Value::Object(map) => match query {
+                Query::Index { .. } => None,
+                Query::Key(key) => map.remove(key.as_ref()),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl QueryRemove for serde_json::Value {}
+
+/// TOML tables resolve key queries and arrays resolve index queries. As TOML has no null, setting
+/// an item past the end of an array is not set rather than filling the gap
+///
+/// ```
+/// # use object_query::{query, Access, QuerySet, SetResult};
+/// let mut value: toml::Value = toml::from_str("[user]\nnames = [\"rob\"]").unwrap();
+/// assert_eq!(value.access(query!["user", "names", -1]), Some(&"rob".into()));
+/// assert_eq!(value.query_set(query!["user", "names", 2], "bob".into()), SetResult::NotSet);
+/// ```
+#[cfg(feature = "toml")]
+impl AccessNext for toml::Value {
+    fn access_next<'a>(&self, query: &Query<'a>) -> Option<&Self> {
+        match self {
+            toml::Value::Array(array) => array.access_next(query),
+            toml::Value::Table(table) => match query {
+                Query::Index { .. } => None,
+                Query::Key(key) => table.get(key.as_ref()),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl Access for toml::Value {}
+
+#[cfg(feature = "toml")]
+impl AccessNextMut for toml::Value {
+    fn access_next_mut<'a>(&mut self, query: &Query<'a>) -> Option<&mut Self> {
+        match self {
+            toml::Value::Array(array) => array.access_next_mut(query),
+            toml::Value::Table(table) => match query {
+                Query::Index { .. } => None,
+                Query::Key(key) => table.get_mut(key.as_ref()),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl AccessMut for toml::Value {}
+
+#[cfg(feature = "toml")]
+impl AccessNextOwned for toml::Value {
+    fn access_next_owned<'a>(self, query: &Query<'a>) -> Option<Self> {
+        match self {
+            toml::Value::Array(array) => array.access_next_owned(query),
+            toml::Value::Table(mut table) => match query {
+                Query::Index { .. } => None,
+                Query::Key(key) => table.remove(key.as_ref()),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl AccessOwned for toml::Value {}
+
+#[cfg(feature = "toml")]
+impl QuerySetItem for toml::Value {
+    fn query_set_item<'a>(&mut self, query: &Query<'a>, val: Self) -> SetResult<Self> {
+        match self {
+            toml::Value::Array(array) => match list_index(query, array.len()) {
+                Some(index) if index == array.len() => {
+                    array.push(val);
+                    SetResult::Set
+                }
+                Some(index) if index < array.len() => {
+                    SetResult::Replaced(core::mem::replace(&mut array[index], val))
+                }
+                _ => SetResult::NotSet,
+            },
+            toml::Value::Table(table) => match query {
+                Query::Index { .. } => SetResult::NotSet,
+                Query::Key(key) => match table.insert(key.to_string(), val) {
+                    Some(res) => SetResult::Replaced(res),
+                    None => SetResult::Set,
+                },
+            },
+            _ => SetResult::NotSet,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl QuerySet for toml::Value {}
+
+#[cfg(feature = "toml")]
+impl QueryCreateItem for toml::Value {
+    fn query_create_item<'a>(query: &Query<'a>) -> Self {
+        match query {
+            Query::Index { .. } => toml::Value::Array(Vec::new()),
+            Query::Key(_) => toml::Value::Table(toml::value::Table::new()),
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl QuerySetCreate for toml::Value {}
+
+#[cfg(feature = "toml")]
+impl QueryRemoveItem for toml::Value {
+    fn query_remove_item<'a>(&mut self, query: &Query<'a>) -> Option<Self> {
+        match self {
+            toml::Value::Array(array) => {
+                let index = list_index(query, array.len())?;
+                if index < array.len() {
+                    Some(array.remove(index))
+                } else {
+                    None
+                }
+            }
+            toml::Value::Table(table) => match query {
+                Query::Index { .. } => None,
+                Query::Key(key) => table.remove(key.as_ref()),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl QueryRemove for toml::Value {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(feature = "json")]
+    use alloc::collections::BTreeSet;
     use alloc::vec;
     #[cfg(feature = "json")]
     use serde_json::json;
+    #[cfg(feature = "toml")]
+    use toml::Value as Toml;
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "json"))]
+    fn serde_round_trip() {
+        let queries = crate::query!["users", 0, -1, "name", 2, -3];
+        let string = serde_json::to_string(&queries).unwrap();
+        assert_eq!(string, r#"["users",0,-1,"name",2,-3]"#);
+        let parsed: Vec<Query<'static>> = serde_json::from_str(&string).unwrap();
+        assert_eq!(parsed, queries);
+
+        assert_eq!(
+            serde_json::from_value::<Query>(json!(-1)).unwrap(),
+            Query::index_from_last(0)
+        );
+        assert!(serde_json::from_value::<Query>(json!(1.5)).is_err());
+        assert!(serde_json::from_value::<Query>(json!(null)).is_err());
+
+        let max = Query::index(usize::MAX);
+        let string = serde_json::to_string(&max).unwrap();
+        assert_eq!(string, usize::MAX.to_string());
+        assert_eq!(serde_json::from_str::<Query>(&string).unwrap(), max);
+        assert!(serde_json::to_string(&Query::index_from_last(usize::MAX)).is_err());
+    }
+
+    #[test]
+    fn index_predicates() {
+        let front = Query::index(2);
+        assert!(front.is_index());
+        assert!(front.is_index_from_front());
+        assert!(!front.is_from_last());
+        assert_eq!(front.as_usize_index(), Some((2, false)));
+        assert_eq!(front.as_index(), Some(2));
+
+        let back = Query::index_from_last(2);
+        assert!(back.is_index());
+        assert!(!back.is_index_from_front());
+        assert!(back.is_from_last());
+        assert_eq!(back.as_usize_index(), Some((2, true)));
+        assert_eq!(back.as_index(), Some(-3));
+
+        let key = Query::key("name");
+        assert!(!key.is_index_from_front());
+        assert!(!key.is_from_last());
+        assert_eq!(key.as_usize_index(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn access_verbose_json() {
+        let value = json!({"users": [{"name": "rob"}], "count": 1});
+        assert_eq!(
+            value.access_verbose(query!["users", 0, "name"]),
+            Ok(&json!("rob"))
+        );
+        assert_eq!(value.access_verbose(query![]), Ok(&value));
+        assert_eq!(
+            value.access_verbose(query!["count", "name"]),
+            Err((1, &Query::key("name")))
+        );
+        assert_eq!(
+            value.access_verbose(query!["users", -2, "name"]),
+            Err((1, &Query::index_from_last(1)))
+        );
+        assert_eq!(
+            value.access_verbose(query!["missing"]),
+            Err((0, &Query::key("missing")))
+        );
+    }
+
+    #[test]
+    fn first_and_last() {
+        assert_eq!(Query::first(), Query::index(0));
+        assert_eq!(Query::first(), Query::from(0));
+        assert_eq!(Query::last(), Query::index_from_last(0));
+        assert_eq!(Query::last(), Query::from(-1));
+        assert_ne!(Query::first(), Query::last());
+        assert_eq!(Query::last().as_index(), Some(-1));
+
+        let list = vec![1, 2, 3];
+        assert_eq!(list.access_next(&Query::first()), Some(&1));
+        assert_eq!(list.access_next(&Query::last()), Some(&3));
+        assert_eq!(Vec::<u8>::new().access_next(&Query::last()), None);
+    }
+
+    #[test]
+    fn access_without_allocation() {
+        // a tree borrowing all of its contents, as it could be on a target without a heap
+        enum Node<'a> {
+            Leaf(u8),
+            List(&'a [Node<'a>]),
+            Map(&'a [(&'a str, Node<'a>)]),
+        }
+
+        impl<'a> AccessNext for Node<'a> {
+            fn access_next<'b>(&self, query: &Query<'b>) -> Option<&Self> {
+                match (self, query) {
+                    (Node::List(items), Query::Index { .. }) => items.access_next(query),
+                    (Node::Map(entries), Query::Key(key)) => entries
+                        .iter()
+                        .find(|(name, _)| name == key)
+                        .map(|(_, node)| node),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<'a> Access for Node<'a> {}
+
+        const PATH: &[Query<'static>] = &[Query::key("readings"), Query::last()];
+        const MISSING: &[Query<'static>] = &[Query::key("readings"), Query::index(3)];
+
+        let readings = [Node::Leaf(1), Node::Leaf(2), Node::Leaf(3)];
+        let entries = [("readings", Node::List(&readings))];
+        let root = Node::Map(&entries);
+        assert!(matches!(root.access(PATH), Some(Node::Leaf(3))));
+        assert!(root.access(MISSING).is_none());
+        assert_eq!(root.access_verbose(MISSING).err(), Some((1, &MISSING[1])));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut queries = vec![
+            Query::index(2),
+            Query::key("b"),
+            Query::index_from_last(0),
+            Query::index(0),
+            Query::key("a"),
+            Query::index_from_last(2),
+            Query::key_owned("B".to_string()),
+            Query::index(usize::MAX),
+        ];
+        queries.sort();
+        assert_eq!(
+            queries,
+            vec![
+                Query::key("B"),
+                Query::key("a"),
+                Query::key("b"),
+                Query::index_from_last(2),
+                Query::index_from_last(0),
+                Query::index(0),
+                Query::index(2),
+                Query::index(usize::MAX),
+            ]
+        );
+        let as_indices = queries.iter().filter_map(Query::as_index).take(4);
+        assert!(as_indices
+            .clone()
+            .zip(as_indices.skip(1))
+            .all(|(a, b)| a < b));
+
+        let set = vec![
+            query!["a", 0].to_vec(),
+            query!["a"].to_vec(),
+            query!["a", -1].to_vec(),
+            query!["a", 0].to_vec(),
+        ]
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![
+                query!["a"].to_vec(),
+                query!["a", -1].to_vec(),
+                query!["a", 0].to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn compare_with_str() {
+        assert_eq!(Query::key("name"), "name");
+        assert_eq!(Query::key_owned("name".to_string()), *"name");
+        assert_ne!(Query::key("name"), "other");
+        assert_ne!(Query::key("name"), "");
+        assert_ne!(Query::index(0), "0");
+        assert_ne!(Query::index_from_last(0), "-1");
+        assert!(crate::query!["a", 0].iter().any(|query| query == "a"));
+    }
+
+    #[test]
+    fn access_list() {
+        let mut list = vec![1, 2, 3];
+        assert_eq!(list.access_next(&Query::index(0)), Some(&1));
+        assert_eq!(list.access_next(&Query::index(2)), Some(&3));
+        assert_eq!(list.access_next(&Query::index(3)), None);
+        assert_eq!(list.access_next(&Query::index_from_last(0)), Some(&3));
+        assert_eq!(list.access_next(&Query::index_from_last(2)), Some(&1));
+        assert_eq!(list.access_next(&Query::index_from_last(3)), None);
+        assert_eq!(list.access_next(&Query::index_from_last(usize::MAX)), None);
+        assert_eq!(list.access_next(&Query::key("0")), None);
+
+        *list.access_next_mut(&Query::index_from_last(0)).unwrap() = 4;
+        assert_eq!(list, vec![1, 2, 4]);
+        assert_eq!(list.access_next_mut(&Query::key("last")), None);
+
+        let slice: &[i32] = &list;
+        assert_eq!(slice.access_next(&Query::index(1)), Some(&2));
+        let array = [1, 2, 3];
+        assert_eq!(array.access_next(&Query::index_from_last(1)), Some(&2));
+
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(empty.access_next(&Query::index_from_last(0)), None);
+
+        assert_eq!(list.clone().access_next_owned(&Query::index(1)), Some(2));
+        assert_eq!(list.access_next_owned(&Query::index_from_last(5)), None);
+    }
+
+    #[test]
+    fn access_btree_map() {
+        let mut inner = BTreeMap::new();
+        inner.insert("b".to_string(), 1);
+        let mut map = BTreeMap::new();
+        map.insert("a".to_string(), inner);
+
+        let path = query!["a", "b"];
+        assert_eq!(
+            map.access_next(&path[0])
+                .and_then(|inner| inner.access_next(&path[1])),
+            Some(&1)
+        );
+        if let Some(value) = map
+            .access_next_mut(&path[0])
+            .and_then(|inner| inner.access_next_mut(&path[1]))
+        {
+            *value = 2;
+        }
+        assert_eq!(map["a"]["b"], 2);
+        assert_eq!(
+            map.clone()
+                .access_next_owned(&path[0])
+                .and_then(|inner| inner.access_next_owned(&path[1])),
+            Some(2)
+        );
+
+        assert_eq!(map.access_next(&Query::key("c")), None);
+        assert_eq!(map.access_next(&Query::index(0)), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn access_hash_map() {
+        let mut inner = HashMap::new();
+        inner.insert("b".to_string(), 1);
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), inner);
+
+        let path = query!["a", "b"];
+        assert_eq!(
+            map.access_next(&path[0])
+                .and_then(|inner| inner.access_next(&path[1])),
+            Some(&1)
+        );
+        if let Some(value) = map
+            .access_next_mut(&path[0])
+            .and_then(|inner| inner.access_next_mut(&path[1]))
+        {
+            *value = 2;
+        }
+        assert_eq!(map["a"]["b"], 2);
+        assert_eq!(
+            map.clone()
+                .access_next_owned(&path[0])
+                .and_then(|inner| inner.access_next_owned(&path[1])),
+            Some(2)
+        );
+
+        assert_eq!(map.access_next(&Query::key("c")), None);
+        assert_eq!(map.access_next(&Query::index(0)), None);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn access_map_of_json() {
+        let mut map = BTreeMap::new();
+        map.insert("users", json!([{"name": "rob"}, {"name": "dave"}]));
+        let path = query!["users", -1, "name"];
+        assert_eq!(
+            map.access_next(&path[0])
+                .and_then(|users| users.access(&path[1..])),
+            Some(&json!("dave"))
+        );
+    }
 
     #[cfg(feature = "json")]
     #[test]
@@ -585,6 +1479,69 @@ mod tests {
         assert_eq!(value, json!({"a": 3, "b": 2, "c": 4}));
     }
 
+    #[cfg(feature = "json")]
+    #[test]
+    fn query_set_create_json() {
+        let mut value = json!({});
+
+        assert_eq!(
+            value.query_set_create(query!["a", "b", "c"], json!(1)),
+            SetResult::Set
+        );
+        assert_eq!(value, json!({"a": {"b": {"c": 1}}}));
+
+        assert_eq!(
+            value.query_set_create(query!["a", "list", 1, "name"], json!(2)),
+            SetResult::Set
+        );
+        assert_eq!(
+            value,
+            json!({"a": {"b": {"c": 1}, "list": [null, {"name": 2}]}})
+        );
+
+        assert_eq!(
+            value.query_set_create(query!["a", "b", "c"], json!(3)),
+            SetResult::Replaced(json!(1))
+        );
+        assert_eq!(
+            value.query_set_create(query!["a", "list", -1, "id"], json!(4)),
+            SetResult::Set
+        );
+        assert_eq!(
+            value,
+            json!({"a": {"b": {"c": 3}, "list": [null, {"name": 2, "id": 4}]}})
+        );
+
+        // existing values are not replaced by containers
+        assert_eq!(
+            value.query_set_create(query!["a", "b", "c", "d"], json!(5)),
+            SetResult::NotSet
+        );
+        assert_eq!(
+            value.query_set_create(query!["a", "list", 0, "d"], json!(5)),
+            SetResult::NotSet
+        );
+        assert_eq!(
+            value.query_set_create(query!["a", 0], json!(5)),
+            SetResult::NotSet
+        );
+        assert_eq!(
+            value,
+            json!({"a": {"b": {"c": 3}, "list": [null, {"name": 2, "id": 4}]}})
+        );
+
+        let mut value = json!([]);
+        assert_eq!(
+            value.query_set_create(query![0, 0], json!(6)),
+            SetResult::Set
+        );
+        assert_eq!(value, json!([[6]]));
+        assert_eq!(
+            value.query_set_create(query![], json!(7)),
+            SetResult::NotSet
+        );
+    }
+
     #[cfg(feature = "json")]
     #[test]
     fn query_set_json_nested() {
@@ -614,4 +1571,152 @@ mod tests {
         );
         assert_eq!(value, json!([{"a": 2}, [7, 3, 6], {"c": 4, "b": 5}]));
     }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn query_remove_json_array() {
+        let mut value = json!([1, 2, 3, 4]);
+
+        assert_eq!(value.query_remove(query![0]), Some(json!(1)));
+        assert_eq!(value, json!([2, 3, 4]));
+
+        assert_eq!(value.query_remove(query![-1]), Some(json!(4)));
+        assert_eq!(value, json!([2, 3]));
+
+        assert_eq!(value.query_remove(query![2]), None);
+        assert_eq!(value.query_remove(query![-3]), None);
+        assert_eq!(value.query_remove(query!["a"]), None);
+        assert_eq!(value, json!([2, 3]));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn query_remove_json_nested() {
+        let mut value = json!([{"a": 1, "b": 2}, [2, 3, 4]]);
+
+        assert_eq!(value.query_remove(query![0, "a"]), Some(json!(1)));
+        assert_eq!(value, json!([{"b": 2}, [2, 3, 4]]));
+
+        assert_eq!(value.query_remove(query![1, 0]), Some(json!(2)));
+        assert_eq!(value, json!([{"b": 2}, [3, 4]]));
+
+        assert_eq!(value.query_remove(query![0, "c"]), None);
+        assert_eq!(value.query_remove(query![2, "b"]), None);
+        assert_eq!(value.query_remove(&[]), None);
+        assert_eq!(value, json!([{"b": 2}, [3, 4]]));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn access_toml_table() {
+        let mut value: Toml = toml::from_str("a = 1\nb = 2").unwrap();
+        let query = vec!["a".into()];
+        assert_eq!(value.access(&query), Some(&Toml::from(1)));
+        assert_eq!(value.access_mut(&query), Some(&mut Toml::from(1)));
+        assert_eq!(value.clone().access_owned(&query), Some(Toml::from(1)));
+        let query = vec!["b".into()];
+        assert_eq!(value.access(&query), Some(&Toml::from(2)));
+        let query = vec!["c".into()];
+        assert_eq!(value.access(&query), None);
+        let query = vec![0.into()];
+        assert_eq!(value.access(&query), None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn access_toml_array() {
+        let mut value = Toml::from(vec![1, 2, 3]);
+        let query = vec![0.into()];
+        assert_eq!(value.access(&query), Some(&Toml::from(1)));
+        assert_eq!(value.access_mut(&query), Some(&mut Toml::from(1)));
+        assert_eq!(value.clone().access_owned(&query), Some(Toml::from(1)));
+        let query = vec![(-1).into()];
+        assert_eq!(value.access(&query), Some(&Toml::from(3)));
+        let query = vec![(-4).into()];
+        assert_eq!(value.access(&query), None);
+        let query = vec![4.into()];
+        assert_eq!(value.access(&query), None);
+        let query = vec!["a".into()];
+        assert_eq!(value.access(&query), None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn access_toml_nested() {
+        let value: Toml =
+            toml::from_str("[[users]]\nname = \"rob\"\ntags = [\"a\", \"b\"]").unwrap();
+        assert_eq!(
+            value.access(query!["users", 0, "name"]),
+            Some(&Toml::from("rob"))
+        );
+        assert_eq!(
+            value.access(query!["users", -1, "tags", -1]),
+            Some(&Toml::from("b"))
+        );
+        assert_eq!(value.access(query!["users", 1, "name"]), None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn query_set_toml_array() {
+        let mut value = Toml::from(vec![1, 2, 3]);
+
+        assert_eq!(
+            value.query_set(query![0], Toml::from(4)),
+            SetResult::Replaced(Toml::from(1))
+        );
+        assert_eq!(value, Toml::from(vec![4, 2, 3]));
+
+        assert_eq!(value.query_set(query![3], Toml::from(5)), SetResult::Set);
+        assert_eq!(value, Toml::from(vec![4, 2, 3, 5]));
+
+        // there is no null to fill the gap with
+        assert_eq!(value.query_set(query![5], Toml::from(6)), SetResult::NotSet);
+        assert_eq!(value, Toml::from(vec![4, 2, 3, 5]));
+
+        assert_eq!(
+            value.query_set(query![-1], Toml::from(7)),
+            SetResult::Replaced(Toml::from(5))
+        );
+        assert_eq!(value, Toml::from(vec![4, 2, 3, 7]));
+
+        assert_eq!(
+            value.query_set(query![-5], Toml::from(9)),
+            SetResult::NotSet
+        );
+        assert_eq!(
+            value.query_set(query!["a"], Toml::from(9)),
+            SetResult::NotSet
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn query_set_toml_table() {
+        let mut value: Toml = toml::from_str("a = 1\nb = 2").unwrap();
+
+        assert_eq!(
+            value.query_set(query!["a"], Toml::from(3)),
+            SetResult::Replaced(Toml::from(1))
+        );
+        assert_eq!(value.query_set(query!["c"], Toml::from(4)), SetResult::Set);
+        assert_eq!(value, toml::from_str("a = 3\nb = 2\nc = 4").unwrap());
+        assert_eq!(value.query_set(query![0], Toml::from(5)), SetResult::NotSet);
+
+        assert_eq!(
+            value.query_set_create(query!["d", "e", 0], Toml::from(6)),
+            SetResult::Set
+        );
+        assert_eq!(value.access(query!["d", "e", 0]), Some(&Toml::from(6)));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn query_remove_toml() {
+        let mut value: Toml = toml::from_str("a = [1, 2, 3]\nb = 4").unwrap();
+        assert_eq!(value.query_remove(query!["a", -1]), Some(Toml::from(3)));
+        assert_eq!(value.query_remove(query!["a", 2]), None);
+        assert_eq!(value.query_remove(query!["b"]), Some(Toml::from(4)));
+        assert_eq!(value, toml::from_str("a = [1, 2]").unwrap());
+    }
 }
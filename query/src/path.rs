@@ -0,0 +1,271 @@
+use crate::Query;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::Peekable;
+use core::str::CharIndices;
+
+/// An Error which may occur when parsing a query path. Each variant holds the byte index of the
+/// error in the path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A `[` was not closed or a `]` was found without an opening `[`
+    UnbalancedBracket(usize),
+    /// The contents of the brackets were neither a quoted key nor an integer
+    InvalidIndex(usize),
+    /// A key between dots was empty
+    EmptyKey(usize),
+    /// A quoted key was not closed
+    UnterminatedString(usize),
+    /// A segment was not separated by a `.` or `[`
+    UnexpectedChar(usize),
+}
+
+type Chars<'a> = Peekable<CharIndices<'a>>;
+
+/// Parse a query path with the compact grammar of [`Query::parse_path`], e.g. `a.b[0]`. This is
+/// the single entry point for turning a string into a list of queries. Natural language queries
+/// such as `the first item of the \`users\`` are parsed by the `nloq` crate instead, which builds
+/// on this one
+///
+/// ```
+/// # use object_query::{parse, query};
+/// assert_eq!(parse("users[0].name").unwrap(), query!["users", 0, "name"]);
+/// ```
+pub fn parse(path: &str) -> Result<Vec<Query<'static>>, ParseError> {
+    Query::parse_path(path)
+}
+
+impl Query<'static> {
+    /// Parse a compact query path such as `a.b[0]["key with space"][-1]`. Keys are separated by
+    /// `.` and brackets hold either a double quoted key (where `\` escapes the next character) or
+    /// an integer index. Negative indices are indices from the last item, so `[-1]` is the last
+    /// item
+    ///
+    /// ```
+    /// # use object_query::{query, Query};
+    /// assert_eq!(
+    ///     Query::parse_path(r#"users[-1]["first name"]"#).unwrap(),
+    ///     query!["users", -1, "first name"]
+    /// );
+    /// ```
+    pub fn parse_path(path: &str) -> Result<Vec<Query<'static>>, ParseError> {
+        let mut out = Vec::new();
+        let mut chars = path.char_indices().peekable();
+        match chars.peek() {
+            None => return Ok(out),
+            Some((_, '[')) => (),
+            Some((i, _)) => {
+                let i = *i;
+                out.push(parse_key(&mut chars, i)?)
+            }
+        }
+        while let Some((i, ch)) = chars.next() {
+            match ch {
+                '.' => out.push(parse_key(&mut chars, i + 1)?),
+                '[' => out.push(parse_bracket(&mut chars, i)?),
+                ']' => return Err(ParseError::UnbalancedBracket(i)),
+                _ => return Err(ParseError::UnexpectedChar(i)),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Interpret a single segment of a query. Digits are an index, a `-` followed by digits is an
+    /// index from the last item where `-1` is the last item and anything else is a key
+    ///
+    /// ```
+    /// # use object_query::Query;
+    /// assert_eq!(Query::from_segment("2"), Query::index(2));
+    /// assert_eq!(Query::from_segment("-1"), Query::index_from_last(0));
+    /// assert_eq!(Query::from_segment("name"), Query::key("name"));
+    /// ```
+    pub fn from_segment(segment: &str) -> Query<'static> {
+        let (digits, from_last) = match segment.strip_prefix('-') {
+            Some(digits) => (digits, true),
+            None => (segment, false),
+        };
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            match digits.parse::<usize>() {
+                Ok(index) if !from_last => return Query::index(index),
+                Ok(index) if index > 0 => return Query::index_from_last(index - 1),
+                _ => (),
+            }
+        }
+        Query::key_owned(segment.to_string())
+    }
+}
+
+fn parse_key(chars: &mut Chars<'_>, start: usize) -> Result<Query<'static>, ParseError> {
+    let mut key = String::new();
+    while let Some((_, ch)) = chars.peek() {
+        if matches!(ch, '.' | '[' | ']') {
+            break;
+        }
+        key.push(*ch);
+        chars.next();
+    }
+    if key.is_empty() {
+        return Err(ParseError::EmptyKey(start));
+    }
+    Ok(Query::key_owned(key))
+}
+
+fn parse_bracket(chars: &mut Chars<'_>, start: usize) -> Result<Query<'static>, ParseError> {
+    let query = if let Some((i, '"')) = chars.peek() {
+        let string_start = *i;
+        chars.next();
+        let mut key = String::new();
+        loop {
+            match chars.next() {
+                Some((_, '"')) => break,
+                Some((_, '\\')) => match chars.next() {
+                    Some((_, ch)) => key.push(ch),
+                    None => return Err(ParseError::UnterminatedString(string_start)),
+                },
+                Some((_, ch)) => key.push(ch),
+                None => return Err(ParseError::UnterminatedString(string_start)),
+            }
+        }
+        Query::key_owned(key)
+    } else {
+        let mut index = String::new();
+        while let Some((_, ch)) = chars.peek() {
+            if *ch == ']' {
+                break;
+            }
+            index.push(*ch);
+            chars.next();
+        }
+        match index.parse::<isize>() {
+            Ok(index) => index.into(),
+            Err(_) if chars.peek().is_none() => return Err(ParseError::UnbalancedBracket(start)),
+            Err(_) => return Err(ParseError::InvalidIndex(start + 1)),
+        }
+    };
+    match chars.next() {
+        Some((_, ']')) => Ok(query),
+        _ => Err(ParseError::UnbalancedBracket(start)),
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnbalancedBracket(i) => {
+                f.write_fmt(format_args!("unbalanced bracket at byte {}", i))
+            }
+            ParseError::InvalidIndex(i) => f.write_fmt(format_args!("invalid index at byte {}", i)),
+            ParseError::EmptyKey(i) => f.write_fmt(format_args!("empty key at byte {}", i)),
+            ParseError::UnterminatedString(i) => {
+                f.write_fmt(format_args!("unterminated string at byte {}", i))
+            }
+            ParseError::UnexpectedChar(i) => {
+                f.write_fmt(format_args!("unexpected character at byte {}", i))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query;
+    use alloc::vec;
+
+    #[test]
+    fn parse_paths() -> Result<(), ParseError> {
+        assert_eq!(Query::parse_path("")?, Vec::<Query>::new());
+        assert_eq!(Query::parse_path("a")?, query!["a"]);
+        assert_eq!(Query::parse_path("a.b")?, query!["a", "b"]);
+        assert_eq!(Query::parse_path("[0]")?, query![0]);
+        assert_eq!(Query::parse_path("a[0]")?, query!["a", 0]);
+        assert_eq!(Query::parse_path("a[0].b")?, query!["a", 0, "b"]);
+        assert_eq!(Query::parse_path("a[-1]")?, query!["a", -1]);
+        assert_eq!(Query::parse_path("a[-3][2]")?, query!["a", -3, 2]);
+        assert_eq!(
+            Query::parse_path(r#"a.b[0]["key with space"][-1]"#)?,
+            query!["a", "b", 0, "key with space", -1]
+        );
+        assert_eq!(
+            Query::parse_path(r#"["dotted.key"]["[bracketed]"]"#)?,
+            query!["dotted.key", "[bracketed]"]
+        );
+        assert_eq!(
+            Query::parse_path(r#"["escaped \" quote"]["back\\slash"]"#)?,
+            query!["escaped \" quote", "back\\slash"]
+        );
+        assert_eq!(Query::parse_path(r#"["0"]"#)?, query!["0"]);
+        assert_eq!(
+            Query::parse_path("a[-1]")?,
+            vec![Query::key("a"), Query::index_from_last(0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_entry_point() -> Result<(), ParseError> {
+        assert_eq!(parse("a.b[0]")?, Query::parse_path("a.b[0]")?);
+        assert_eq!(
+            parse(r#"users[-1]["first name"]"#)?,
+            query!["users", -1, "first name"]
+        );
+        assert_eq!(parse("a..b"), Err(ParseError::EmptyKey(2)));
+        Ok(())
+    }
+
+    #[test]
+    fn from_segments() {
+        assert_eq!(Query::from_segment("0"), Query::index(0));
+        assert_eq!(Query::from_segment("12"), Query::index(12));
+        assert_eq!(Query::from_segment("-1"), Query::index_from_last(0));
+        assert_eq!(Query::from_segment("-3"), Query::index_from_last(2));
+        assert_eq!(Query::from_segment("name"), Query::key("name"));
+        assert_eq!(Query::from_segment(""), Query::key(""));
+        assert_eq!(Query::from_segment("-"), Query::key("-"));
+        assert_eq!(Query::from_segment("-0"), Query::key("-0"));
+        assert_eq!(Query::from_segment("+1"), Query::key("+1"));
+        assert_eq!(Query::from_segment("1a"), Query::key("1a"));
+        assert_eq!(Query::from_segment(" 1"), Query::key(" 1"));
+        assert_eq!(
+            Query::from_segment("99999999999999999999999"),
+            Query::key("99999999999999999999999")
+        );
+    }
+
+    #[test]
+    fn parse_path_errors() {
+        assert_eq!(
+            Query::parse_path("a[0"),
+            Err(ParseError::UnbalancedBracket(1))
+        );
+        assert_eq!(
+            Query::parse_path("a["),
+            Err(ParseError::UnbalancedBracket(1))
+        );
+        assert_eq!(
+            Query::parse_path("a]"),
+            Err(ParseError::UnbalancedBracket(1))
+        );
+        assert_eq!(
+            Query::parse_path(r#"a["b""#),
+            Err(ParseError::UnbalancedBracket(1))
+        );
+        assert_eq!(
+            Query::parse_path(r#"a["b]"#),
+            Err(ParseError::UnterminatedString(2))
+        );
+        assert_eq!(Query::parse_path("a[b]"), Err(ParseError::InvalidIndex(2)));
+        assert_eq!(Query::parse_path("a[]"), Err(ParseError::InvalidIndex(2)));
+        assert_eq!(Query::parse_path("a..b"), Err(ParseError::EmptyKey(2)));
+        assert_eq!(Query::parse_path(".a"), Err(ParseError::EmptyKey(0)));
+        assert_eq!(Query::parse_path("a."), Err(ParseError::EmptyKey(2)));
+        assert_eq!(
+            Query::parse_path("[0]a"),
+            Err(ParseError::UnexpectedChar(3))
+        );
+    }
+}